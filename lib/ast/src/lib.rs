@@ -1,7 +1,9 @@
+mod line_index;
 mod markdown;
 mod node;
 mod node_children_iterator;
 
+pub use line_index::*;
 pub use node::*;
 pub use node_children_iterator::*;
 
@@ -0,0 +1,135 @@
+use std::ops::Range;
+
+/// The unit columns are counted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Columns count bytes, as do the ranges of [Node](crate::Node)s.
+    Utf8,
+    /// Columns count UTF-16 code units, as the Language Server Protocol does.
+    Utf16,
+}
+
+/// A 1-based line and column position in a source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LineColumn {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl LineColumn {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// Converts byte offsets of a source to line and column positions, and back.
+///
+/// The start of every line is computed once, when the index is built, so that every
+/// conversion only has to look at a single line.
+#[derive(Debug, Clone)]
+pub struct LineIndex<'source> {
+    source: &'source str,
+    /// The byte offset of the start of every line, the first one being 0.
+    line_starts: Vec<usize>,
+}
+
+impl<'source> LineIndex<'source> {
+    pub fn new(source: &'source str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    /// The position of a byte offset. Offsets past the end are clamped to it.
+    pub fn line_column(&self, offset: usize, encoding: Encoding) -> LineColumn {
+        let offset = offset.min(self.source.len());
+        let line = self
+            .line_starts
+            .partition_point(|start| *start <= offset)
+            .saturating_sub(1);
+        let before = &self.source[self.line_starts[line]..offset];
+        let column = match encoding {
+            Encoding::Utf8 => before.len(),
+            Encoding::Utf16 => before.encode_utf16().count(),
+        };
+        LineColumn::new(line + 1, column + 1)
+    }
+
+    /// The positions of the start and the end of a byte range.
+    pub fn span(&self, range: &Range<usize>, encoding: Encoding) -> Range<LineColumn> {
+        self.line_column(range.start, encoding)..self.line_column(range.end, encoding)
+    }
+
+    /// The byte offset of a position. Positions past the end of their line are clamped to
+    /// it, and positions past the last line to the end of the source.
+    pub fn offset(&self, position: LineColumn, encoding: Encoding) -> usize {
+        let Some(start) = self.line_starts.get(position.line.saturating_sub(1)) else {
+            return self.source.len();
+        };
+        let line = self.source[*start..].split('\n').next().unwrap_or_default();
+        let column = position.column.saturating_sub(1);
+        let mut units = 0;
+        for (index, c) in line.char_indices() {
+            if units >= column {
+                return start + index;
+            }
+            units += match encoding {
+                Encoding::Utf8 => c.len_utf8(),
+                Encoding::Utf16 => c.len_utf16(),
+            };
+        }
+        start + line.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = "# Title\n\n- 😀 bull\n";
+
+    #[test]
+    fn should_count_lines_and_columns_from_1() {
+        let index = LineIndex::new(SOURCE);
+        assert_eq!(index.line_column(0, Encoding::Utf8), LineColumn::new(1, 1));
+        assert_eq!(index.line_column(8, Encoding::Utf8), LineColumn::new(2, 1));
+        assert_eq!(
+            index.line_column(SOURCE.len(), Encoding::Utf8),
+            LineColumn::new(4, 1)
+        );
+    }
+
+    #[test]
+    fn should_count_columns_in_encoding_units() {
+        let index = LineIndex::new(SOURCE);
+        let bull = SOURCE.find("bull").unwrap();
+        assert_eq!(
+            index.line_column(bull, Encoding::Utf8),
+            LineColumn::new(3, 8)
+        );
+        assert_eq!(
+            index.line_column(bull, Encoding::Utf16),
+            LineColumn::new(3, 6)
+        );
+    }
+
+    #[test]
+    fn should_convert_positions_back_to_offsets() {
+        let index = LineIndex::new(SOURCE);
+        let bull = SOURCE.find("bull").unwrap();
+        assert_eq!(index.offset(LineColumn::new(3, 8), Encoding::Utf8), bull);
+        assert_eq!(index.offset(LineColumn::new(3, 6), Encoding::Utf16), bull);
+        assert_eq!(
+            index.offset(LineColumn::new(1, 99), Encoding::Utf8),
+            "# Title".len()
+        );
+        assert_eq!(
+            index.offset(LineColumn::new(9, 1), Encoding::Utf16),
+            SOURCE.len()
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::Write;
+
+use crate::{Changelog, render::escape};
+
+impl<'source> Changelog<'source> {
+    /// Renders the releases of the changelog as the `<releases>` element of an AppStream
+    /// metainfo file.
+    ///
+    /// Every change set becomes a paragraph followed by the list of its entries, as AppStream
+    /// descriptions only allow a small subset of HTML. Pre-releases are marked as development
+    /// releases, and yanked releases are left out since they shouldn't be offered to users.
+    pub fn to_appstream(&self) -> String {
+        let mut output = String::new();
+        writeln!(output, "<releases>").unwrap();
+        for release in self
+            .releases()
+            .iter()
+            .filter(|release| !release.is_yanked())
+        {
+            let version = release.version().to_string();
+            write!(
+                output,
+                "  <release version=\"{}\" date=\"{}\"",
+                escape(&version),
+                release.date()
+            )
+            .unwrap();
+            if !release.version().pre.is_empty() {
+                write!(output, " type=\"development\"").unwrap();
+            }
+            writeln!(output, ">").unwrap();
+            writeln!(output, "    <description>").unwrap();
+            for change_set in release.changes().iter() {
+                writeln!(output, "      <p>{}</p>", change_set.kind()).unwrap();
+                writeln!(output, "      <ul>").unwrap();
+                for change in change_set.items() {
+                    // Line breaks aren't meaningful in AppStream text.
+                    let text = change
+                        .text()
+                        .lines()
+                        .map(str::trim)
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    writeln!(output, "        <li>{}</li>", escape(&text)).unwrap();
+                }
+                writeln!(output, "      </ul>").unwrap();
+            }
+            writeln!(output, "    </description>").unwrap();
+            if let Some(reference_definition) = self
+                .reference_definitions()
+                .iter()
+                .find(|reference_definition| reference_definition.label() == version)
+            {
+                writeln!(
+                    output,
+                    "    <url type=\"details\">{}</url>",
+                    escape(reference_definition.dest())
+                )
+                .unwrap();
+            }
+            writeln!(output, "  </release>").unwrap();
+        }
+        writeln!(output, "</releases>").unwrap();
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod to_appstream {
+        use super::*;
+
+        #[test]
+        fn should_render_releases() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Not yet.
+
+## [0.3.0-rc.1] - 2026-03-01
+
+### Fixed
+
+- The `bull` & co.
+
+## [0.2.0] - 2026-02-04 [YANKED]
+
+### Removed
+
+- The bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull,
+  on two lines.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.3.0-rc.1...HEAD
+[0.3.0-rc.1]: https://github.com/owner/repo/compare/v0.2.0...v0.3.0-rc.1
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+            )
+            .unwrap();
+            assert_eq!(
+                changelog.to_appstream(),
+                r#"<releases>
+  <release version="0.3.0-rc.1" date="2026-03-01" type="development">
+    <description>
+      <p>Fixed</p>
+      <ul>
+        <li>The `bull` &amp; co.</li>
+      </ul>
+    </description>
+    <url type="details">https://github.com/owner/repo/compare/v0.2.0...v0.3.0-rc.1</url>
+  </release>
+  <release version="0.1.0" date="2026-01-01">
+    <description>
+      <p>Added</p>
+      <ul>
+        <li>Some bull, on two lines.</li>
+      </ul>
+    </description>
+    <url type="details">https://github.com/owner/repo/releases/tag/v0.1.0</url>
+  </release>
+</releases>
+"#
+            );
+        }
+    }
+}
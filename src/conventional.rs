@@ -0,0 +1,211 @@
+use crate::{ChangeSetKind, Changelog};
+
+/// Maps a [conventional commit](https://www.conventionalcommits.org/en/v1.0.0/) message to
+/// a changelog entry.
+///
+/// `feat` commits are added, `fix` commits are fixed, `deprecate` commits are deprecated and
+/// `security` commits are security fixes. Breaking changes, marked with `!` or a
+/// `BREAKING CHANGE:` footer, are changes whatever their type. Other types, like `chore` or
+/// `docs`, and non conventional messages don't make it into the changelog.
+pub fn conventional_entry(message: &str) -> Option<(ChangeSetKind, String)> {
+    let header = message.lines().next()?;
+    let (prefix, description) = header.split_once(':')?;
+    let description = description.trim();
+    if description.is_empty() {
+        return None;
+    }
+
+    let (prefix, bang) = match prefix.strip_suffix('!') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+    let commit_type = match prefix.split_once('(') {
+        Some((commit_type, scope)) if scope.ends_with(')') => commit_type,
+        Some(_) => return None,
+        None => prefix,
+    };
+    if commit_type.is_empty() || !commit_type.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let breaking = bang
+        || message.lines().any(|line| {
+            line.starts_with("BREAKING CHANGE:") || line.starts_with("BREAKING-CHANGE:")
+        });
+    let kind = if breaking {
+        ChangeSetKind::Changed
+    } else {
+        match commit_type.to_ascii_lowercase().as_str() {
+            "feat" => ChangeSetKind::Added,
+            "fix" => ChangeSetKind::Fixed,
+            "deprecate" => ChangeSetKind::Deprecated,
+            "security" => ChangeSetKind::Security,
+            _ => return None,
+        }
+    };
+
+    let mut text = description.to_string();
+    if let Some(first) = text.get(0..1) {
+        text.replace_range(0..1, &first.to_uppercase());
+    }
+    Some((kind, text))
+}
+
+// The pull request number, like `#123`, GitHub appends to squashed commits.
+fn pull_request_number(text: &str) -> Option<&str> {
+    let start = text.rfind('#')?;
+    let digits = text[start + 1..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(text.len(), |end| start + 1 + end);
+    (digits > start + 1).then(|| &text[start..digits])
+}
+
+// Whether the entry mentions the pull request number, which `#12` doesn't for `#1`.
+fn mentions_number(entry: &str, number: &str) -> bool {
+    entry
+        .match_indices(number)
+        .any(|(start, _)| !entry[start + number.len()..].starts_with(|c: char| c.is_ascii_digit()))
+}
+
+impl<'source> Changelog<'source> {
+    /// Maps commit messages to the entries missing from the changelog.
+    ///
+    /// A commit is considered already mentioned when an existing entry, released or not,
+    /// contains its description or its pull request number.
+    pub fn entries_from_commits(&self, messages: &[String]) -> Vec<(ChangeSetKind, String)> {
+        let mut existing: Vec<String> = self
+            .unreleased()
+            .iter()
+            .map(|unreleased| unreleased.changes())
+            .chain(self.releases().iter().map(|release| release.changes()))
+            .flat_map(|changes| changes.iter())
+            .flat_map(|change_set| change_set.items())
+            .map(|change| change.text().to_string())
+            .collect();
+
+        let mut entries = vec![];
+        for message in messages {
+            let Some((kind, text)) = conventional_entry(message) else {
+                continue;
+            };
+            let mentioned = existing.iter().any(|entry| {
+                entry.contains(&text)
+                    || pull_request_number(&text)
+                        .is_some_and(|number| mentions_number(entry, number))
+            });
+            if !mentioned {
+                existing.push(text.clone());
+                entries.push((kind, text));
+            }
+        }
+        entries
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod conventional_entry {
+        use super::*;
+
+        #[test]
+        fn should_map_feat_to_added() {
+            assert_eq!(
+                conventional_entry("feat: some bull"),
+                Some((ChangeSetKind::Added, "Some bull".to_string()))
+            );
+        }
+
+        #[test]
+        fn should_map_scoped_fix_to_fixed() {
+            assert_eq!(
+                conventional_entry("fix(parser): the bull (#12)\n\nLong description."),
+                Some((ChangeSetKind::Fixed, "The bull (#12)".to_string()))
+            );
+        }
+
+        #[test]
+        fn should_map_deprecate_and_security() {
+            assert_eq!(
+                conventional_entry("deprecate: the bull").map(|(kind, _)| kind),
+                Some(ChangeSetKind::Deprecated)
+            );
+            assert_eq!(
+                conventional_entry("security: the bull").map(|(kind, _)| kind),
+                Some(ChangeSetKind::Security)
+            );
+        }
+
+        #[test]
+        fn should_map_breaking_changes_to_changed() {
+            assert_eq!(
+                conventional_entry("feat!: new bull").map(|(kind, _)| kind),
+                Some(ChangeSetKind::Changed)
+            );
+            assert_eq!(
+                conventional_entry("fix(api)!: new bull").map(|(kind, _)| kind),
+                Some(ChangeSetKind::Changed)
+            );
+            assert_eq!(
+                conventional_entry("feat: new bull\n\nBREAKING CHANGE: the old bull is gone.")
+                    .map(|(kind, _)| kind),
+                Some(ChangeSetKind::Changed)
+            );
+        }
+
+        #[test]
+        fn should_skip_other_types_and_free_form_messages() {
+            assert_eq!(conventional_entry("chore: bump the bull"), None);
+            assert_eq!(conventional_entry("Fix the bull"), None);
+            assert_eq!(conventional_entry("feat:"), None);
+            assert_eq!(conventional_entry("Merge branch: bull"), None);
+        }
+    }
+
+    mod entries_from_commits {
+        use super::*;
+
+        #[test]
+        fn should_skip_commits_already_mentioned() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Some bull.
+
+## [0.1.0] - 2026-01-01
+
+### Fixed
+
+- The bull, see #12.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+            )
+            .unwrap();
+            let messages = [
+                "feat: some bull",
+                "fix: the other bull (#12)",
+                "fix: x (#1)",
+                "chore: release",
+                "fix: the new bull (#13)",
+                "fix: the new bull (#13)",
+            ]
+            .map(String::from);
+            assert_eq!(
+                changelog.entries_from_commits(&messages),
+                vec![
+                    (ChangeSetKind::Fixed, "X (#1)".to_string()),
+                    (ChangeSetKind::Fixed, "The new bull (#13)".to_string())
+                ]
+            );
+        }
+    }
+}
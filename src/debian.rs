@@ -0,0 +1,426 @@
+use std::{collections::BTreeMap, error::Error, fmt::Display, fmt::Write};
+
+use chrono::{DateTime, NaiveDate};
+use semver::Version;
+
+use crate::{ChangeSetKind, Changelog, ChangelogDocument, ImportError, ReleaseDocument};
+
+/// The package information of a `debian/changelog` that cannot be inferred from the changelog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebianConfig {
+    package: String,
+    maintainer: String,
+    distribution: String,
+    urgency: String,
+}
+
+impl DebianConfig {
+    /// The maintainer is expected in the `Full Name <email@example.com>` form.
+    ///
+    /// The distribution defaults to `unstable`, and the urgency to `medium`.
+    pub fn new(package: String, maintainer: String) -> Self {
+        Self {
+            package,
+            maintainer,
+            distribution: "unstable".to_string(),
+            urgency: "medium".to_string(),
+        }
+    }
+
+    pub fn with_distribution(mut self, distribution: String) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
+    pub fn with_urgency(mut self, urgency: String) -> Self {
+        self.urgency = urgency;
+        self
+    }
+}
+
+impl<'source> Changelog<'source> {
+    /// Renders the releases of the changelog in the `debian/changelog` format.
+    ///
+    /// Every entry is prefixed with the kind of its change set, so that the changelog can be
+    /// imported back with [import_debian]. Since Debian versions sort pre-releases with `~`,
+    /// the `-` of semantic versions are converted. The unreleased section is left out.
+    pub fn to_debian(&self, config: &DebianConfig) -> String {
+        let mut output = String::new();
+        for release in self.releases() {
+            writeln!(
+                output,
+                "{} ({}) {}; urgency={}\n",
+                config.package,
+                release.version().to_string().replace('-', "~"),
+                config.distribution,
+                config.urgency
+            )
+            .unwrap();
+            for change_set in release.changes().iter() {
+                for change in change_set.items() {
+                    let mut lines = change.text().lines();
+                    writeln!(
+                        output,
+                        "  * {}: {}",
+                        change_set.kind(),
+                        lines.next().unwrap_or_default()
+                    )
+                    .unwrap();
+                    for line in lines.filter(|line| !line.trim().is_empty()) {
+                        writeln!(output, "    {}", line.trim()).unwrap();
+                    }
+                }
+            }
+            writeln!(
+                output,
+                "\n -- {}  {}\n",
+                config.maintainer,
+                release.date().format("%a, %d %b %Y 00:00:00 +0000")
+            )
+            .unwrap();
+        }
+        // Only a single trailing new line.
+        output.pop();
+        output
+    }
+}
+
+/// Generates a Keep a Changelog markdown file from a `debian/changelog`.
+///
+/// The repository URL is used to generate the reference definitions, like for [ChangelogDocument].
+/// Entries prefixed with the kind of a change set, like `Added: `, are filed under it, and the
+/// others are filed as changes. The entries of consecutive Debian revisions of the same upstream
+/// version are merged into a single release, dated from the most recent one.
+pub fn import_debian(content: &str, repository: &str) -> Result<String, ImportError> {
+    let mut document = ChangelogDocument::new(repository.to_string());
+    document.releases = parse_debian(content)?;
+    document.to_markdown()
+}
+
+fn parse_debian(content: &str) -> Result<Vec<ReleaseDocument>, DebianParseError> {
+    let mut releases: Vec<ReleaseDocument> = vec![];
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+    while let Some((number, line)) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let version = parse_header(number, line)?;
+        let mut entries: Vec<String> = vec![];
+        let date = loop {
+            let Some((number, line)) = lines.next() else {
+                return Err(DebianParseError::MissingTrailer(number));
+            };
+            if let Some(trailer) = line.strip_prefix(" -- ") {
+                break parse_trailer(number, trailer)?;
+            } else if let Some(entry) = line.strip_prefix("  * ") {
+                entries.push(entry.trim().to_string());
+            } else if line.trim().is_empty() || line.trim_start().starts_with('[') {
+                // Blank lines and the `[ Name ]` contributor markers carry no changes.
+                continue;
+            } else if line.starts_with("    ")
+                && let Some(entry) = entries.last_mut()
+            {
+                entry.push('\n');
+                entry.push_str(line.trim());
+            } else {
+                return Err(DebianParseError::InvalidEntry(number));
+            }
+        };
+
+        let release = match releases.last_mut() {
+            Some(release) if release.version == version => release,
+            _ => {
+                releases.push(ReleaseDocument {
+                    version,
+                    date,
+                    yanked: false,
+                    changes: BTreeMap::new(),
+                });
+                releases.last_mut().unwrap()
+            }
+        };
+        for entry in entries {
+            let (kind, text) = match entry.split_once(": ") {
+                Some((prefix, text)) => match ChangeSetKind::try_from(prefix) {
+                    Ok(kind) => (kind, text.to_string()),
+                    Err(_) => (ChangeSetKind::Changed, entry),
+                },
+                None => (ChangeSetKind::Changed, entry),
+            };
+            release.changes.entry(kind).or_default().push(text);
+        }
+    }
+    Ok(releases)
+}
+
+// Parses the upstream version out of a `package (version) distributions; urgency=...` line.
+fn parse_header(number: usize, line: &str) -> Result<Version, DebianParseError> {
+    let Some((_, rest)) = line.split_once(" (") else {
+        return Err(DebianParseError::InvalidHeader(number));
+    };
+    let Some((version, _)) = rest.split_once(')') else {
+        return Err(DebianParseError::InvalidHeader(number));
+    };
+    // The epoch and the Debian revision aren't part of the upstream version.
+    let version = version
+        .split_once(':')
+        .map_or(version, |(_, version)| version);
+    let version = version
+        .rsplit_once('-')
+        .map_or(version, |(version, _)| version);
+    Version::parse(&version.replace('~', "-")).map_err(|_| DebianParseError::InvalidVersion(number))
+}
+
+// Parses the date out of a ` -- Maintainer <email>  date` line, stripped of its ` -- ` prefix.
+fn parse_trailer(number: usize, trailer: &str) -> Result<NaiveDate, DebianParseError> {
+    let Some((_, date)) = trailer.rsplit_once("  ") else {
+        return Err(DebianParseError::InvalidTrailer(number));
+    };
+    DateTime::parse_from_rfc2822(date.trim())
+        .map(|date| date.date_naive())
+        .map_err(|_| DebianParseError::InvalidTrailer(number))
+}
+
+/// The errors of parsing a `debian/changelog`. They hold the 1-based number of the offending line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebianParseError {
+    InvalidHeader(usize),
+    InvalidVersion(usize),
+    InvalidEntry(usize),
+    InvalidTrailer(usize),
+    MissingTrailer(usize),
+}
+
+impl Display for DebianParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DebianParseError::InvalidHeader(line) => {
+                write!(
+                    f,
+                    "line {}: expected a \"package (version) distribution; urgency=level\" header",
+                    line
+                )
+            }
+            DebianParseError::InvalidVersion(line) => {
+                write!(
+                    f,
+                    "line {}: the upstream version is not a semantic version",
+                    line
+                )
+            }
+            DebianParseError::InvalidEntry(line) => {
+                write!(f, "line {}: expected an entry starting with \"  * \"", line)
+            }
+            DebianParseError::InvalidTrailer(line) => {
+                write!(
+                    f,
+                    "line {}: expected a \" -- maintainer  date\" trailer",
+                    line
+                )
+            }
+            DebianParseError::MissingTrailer(line) => {
+                write!(f, "line {}: the entry has no trailer", line)
+            }
+        }
+    }
+}
+
+impl Error for DebianParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Not yet.
+
+## [0.2.0] - 2026-02-04
+
+### Changed
+
+- The bull, now
+  on two lines.
+
+### Removed
+
+- The bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    const DEBIAN: &str = r"bull (0.2.0) unstable; urgency=medium
+
+  * Changed: The bull, now
+    on two lines.
+  * Removed: The bull.
+
+ -- Bull Master <bull@example.com>  Wed, 04 Feb 2026 00:00:00 +0000
+
+bull (0.1.0) unstable; urgency=medium
+
+  * Added: Some bull.
+
+ -- Bull Master <bull@example.com>  Thu, 01 Jan 2026 00:00:00 +0000
+";
+
+    mod to_debian {
+        use super::*;
+
+        #[test]
+        fn should_render_releases() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let config = DebianConfig::new("bull".into(), "Bull Master <bull@example.com>".into());
+            assert_eq!(changelog.to_debian(&config), DEBIAN);
+        }
+
+        #[test]
+        fn should_use_configured_distribution_and_urgency() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let config = DebianConfig::new("bull".into(), "Bull Master <bull@example.com>".into())
+                .with_distribution("noble".into())
+                .with_urgency("high".into());
+            assert!(
+                changelog
+                    .to_debian(&config)
+                    .starts_with("bull (0.2.0) noble; urgency=high\n")
+            );
+        }
+
+        #[test]
+        fn should_convert_pre_release_separator() {
+            let changelog = Changelog::parse(
+                "# Changelog\n\nIntro.\n\n## [0.1.0-rc.1] - 2026-01-01\n\n### Added\n\n- Some bull.\n\n[0.1.0-rc.1]: https://github.com/owner/repo/releases/tag/v0.1.0-rc.1\n",
+            )
+            .unwrap();
+            let config = DebianConfig::new("bull".into(), "Bull Master <bull@example.com>".into());
+            assert!(
+                changelog
+                    .to_debian(&config)
+                    .starts_with("bull (0.1.0~rc.1) unstable; urgency=medium\n")
+            );
+        }
+    }
+
+    mod import_debian {
+        use super::*;
+
+        #[test]
+        fn should_round_trip_releases() {
+            let markdown = import_debian(DEBIAN, "https://github.com/owner/repo").unwrap();
+            let expected = Changelog::parse(SOURCE).unwrap();
+            let imported = Changelog::parse(&markdown).unwrap();
+            assert_eq!(imported.releases().len(), 2);
+            for (imported, expected) in imported.releases().iter().zip(expected.releases()) {
+                assert_eq!(imported.version(), expected.version());
+                assert_eq!(imported.date(), expected.date());
+                let texts = |release: &crate::Release| {
+                    release
+                        .changes()
+                        .iter()
+                        .flat_map(|change_set| {
+                            change_set
+                                .items()
+                                .iter()
+                                .map(|change| (change_set.kind(), change.text().to_string()))
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(texts(imported), texts(expected));
+            }
+        }
+
+        #[test]
+        fn should_merge_revisions_and_file_unprefixed_entries_as_changes() {
+            let markdown = import_debian(
+                r"bull (1:0.1.0-2) unstable; urgency=low
+
+  [ Bull Master ]
+  * Rebuild against the new bull.
+
+ -- Bull Master <bull@example.com>  Fri, 02 Jan 2026 10:00:00 +0100
+
+bull (1:0.1.0-1) unstable; urgency=low
+
+  * Added: Some bull.
+
+ -- Bull Master <bull@example.com>  Thu, 01 Jan 2026 00:00:00 +0000
+",
+                "https://github.com/owner/repo",
+            )
+            .unwrap();
+            assert!(markdown.contains(
+                r"## [0.1.0] - 2026-01-02
+
+### Added
+
+- Some bull.
+
+### Changed
+
+- Rebuild against the new bull.
+"
+            ));
+        }
+
+        #[test]
+        fn should_convert_pre_release_separator() {
+            let markdown = import_debian(
+                "bull (0.1.0~rc.1-1) unstable; urgency=low\n\n  * Added: Some bull.\n\n -- Bull  Thu, 01 Jan 2026 00:00:00 +0000\n",
+                "https://github.com/owner/repo",
+            )
+            .unwrap();
+            assert!(markdown.contains("## [0.1.0-rc.1] - 2026-01-01\n"));
+        }
+
+        #[test]
+        fn should_error_with_invalid_version() {
+            let result = import_debian(
+                "bull (bull) unstable; urgency=low\n\n  * Stuff.\n\n -- Bull  Thu, 01 Jan 2026 00:00:00 +0000\n",
+                "https://github.com/owner/repo",
+            );
+            assert!(
+                matches!(
+                    result,
+                    Err(ImportError::Debian(DebianParseError::InvalidVersion(1)))
+                ),
+                "{:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn should_error_with_missing_trailer() {
+            let result = import_debian(
+                "bull (0.1.0) unstable; urgency=low\n\n  * Stuff.\n",
+                "https://github.com/owner/repo",
+            );
+            assert!(
+                matches!(
+                    result,
+                    Err(ImportError::Debian(DebianParseError::MissingTrailer(1)))
+                ),
+                "{:?}",
+                result
+            );
+        }
+    }
+}
@@ -0,0 +1,430 @@
+use std::fmt::Display;
+
+use chrono::NaiveDate;
+use semver::Version;
+
+use crate::{ChangeSetKind, Changelog, Changes};
+
+/// A section of a changelog holding changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangelogSection {
+    Unreleased,
+    Release(Version),
+}
+
+impl Display for ChangelogSection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangelogSection::Unreleased => write!(f, "Unreleased"),
+            ChangelogSection::Release(version) => write!(f, "{}", version),
+        }
+    }
+}
+
+/// A semantic difference between two revisions of a changelog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangelogDifference {
+    ReleaseAdded(Version),
+    ReleaseRemoved(Version),
+    DateChanged {
+        version: Version,
+        old: NaiveDate,
+        new: NaiveDate,
+    },
+    YankedChanged {
+        version: Version,
+        yanked: bool,
+    },
+    EntryAdded {
+        section: ChangelogSection,
+        kind: ChangeSetKind,
+        text: String,
+    },
+    EntryRemoved {
+        section: ChangelogSection,
+        kind: ChangeSetKind,
+        text: String,
+    },
+    ReferenceDefinitionAdded {
+        label: String,
+        dest: String,
+    },
+    ReferenceDefinitionRemoved {
+        label: String,
+        dest: String,
+    },
+    ReferenceDefinitionChanged {
+        label: String,
+        old: String,
+        new: String,
+    },
+}
+
+impl Display for ChangelogDifference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangelogDifference::ReleaseAdded(version) => write!(f, "+ release {}", version),
+            ChangelogDifference::ReleaseRemoved(version) => write!(f, "- release {}", version),
+            ChangelogDifference::DateChanged { version, old, new } => {
+                write!(
+                    f,
+                    "~ release {}: date changed from {} to {}",
+                    version, old, new
+                )
+            }
+            ChangelogDifference::YankedChanged { version, yanked } => {
+                let status = if *yanked {
+                    "yanked"
+                } else {
+                    "no longer yanked"
+                };
+                write!(f, "~ release {}: {}", version, status)
+            }
+            ChangelogDifference::EntryAdded {
+                section,
+                kind,
+                text,
+            } => write!(f, "+ {} {}: {}", section, kind, text),
+            ChangelogDifference::EntryRemoved {
+                section,
+                kind,
+                text,
+            } => write!(f, "- {} {}: {}", section, kind, text),
+            ChangelogDifference::ReferenceDefinitionAdded { label, dest } => {
+                write!(f, "+ [{}]: {}", label, dest)
+            }
+            ChangelogDifference::ReferenceDefinitionRemoved { label, dest } => {
+                write!(f, "- [{}]: {}", label, dest)
+            }
+            ChangelogDifference::ReferenceDefinitionChanged { label, old, new } => {
+                write!(f, "~ [{}]: {} -> {}", label, old, new)
+            }
+        }
+    }
+}
+
+/// Compares two revisions of a changelog.
+///
+/// The entries of added and removed releases aren't reported individually. Entries are
+/// compared by text within their section and change set kind, so an edited entry shows up
+/// as one entry removed and another added.
+pub fn diff(old: &Changelog, new: &Changelog) -> Vec<ChangelogDifference> {
+    let mut differences = vec![];
+
+    let empty = Changes::new(vec![]);
+    let old_unreleased = old
+        .unreleased()
+        .as_ref()
+        .map_or(&empty, |unreleased| unreleased.changes());
+    let new_unreleased = new
+        .unreleased()
+        .as_ref()
+        .map_or(&empty, |unreleased| unreleased.changes());
+    diff_changes(
+        &mut differences,
+        ChangelogSection::Unreleased,
+        old_unreleased,
+        new_unreleased,
+    );
+
+    for new_release in new.releases() {
+        let version = new_release.version();
+        let Some(old_release) = old
+            .releases()
+            .iter()
+            .find(|release| release.version() == version)
+        else {
+            differences.push(ChangelogDifference::ReleaseAdded(version.clone()));
+            continue;
+        };
+        if old_release.date() != new_release.date() {
+            differences.push(ChangelogDifference::DateChanged {
+                version: version.clone(),
+                old: *old_release.date(),
+                new: *new_release.date(),
+            });
+        }
+        if old_release.is_yanked() != new_release.is_yanked() {
+            differences.push(ChangelogDifference::YankedChanged {
+                version: version.clone(),
+                yanked: new_release.is_yanked(),
+            });
+        }
+        diff_changes(
+            &mut differences,
+            ChangelogSection::Release(version.clone()),
+            old_release.changes(),
+            new_release.changes(),
+        );
+    }
+    for old_release in old.releases() {
+        if !new
+            .releases()
+            .iter()
+            .any(|release| release.version() == old_release.version())
+        {
+            differences.push(ChangelogDifference::ReleaseRemoved(
+                old_release.version().clone(),
+            ));
+        }
+    }
+
+    for new_definition in new.reference_definitions() {
+        let label = new_definition.label();
+        match old
+            .reference_definitions()
+            .iter()
+            .find(|definition| definition.label() == label)
+        {
+            None => differences.push(ChangelogDifference::ReferenceDefinitionAdded {
+                label: label.to_string(),
+                dest: new_definition.dest().to_string(),
+            }),
+            Some(old_definition) if old_definition.dest() != new_definition.dest() => differences
+                .push(ChangelogDifference::ReferenceDefinitionChanged {
+                    label: label.to_string(),
+                    old: old_definition.dest().to_string(),
+                    new: new_definition.dest().to_string(),
+                }),
+            Some(_) => {}
+        }
+    }
+    for old_definition in old.reference_definitions() {
+        if !new
+            .reference_definitions()
+            .iter()
+            .any(|definition| definition.label() == old_definition.label())
+        {
+            differences.push(ChangelogDifference::ReferenceDefinitionRemoved {
+                label: old_definition.label().to_string(),
+                dest: old_definition.dest().to_string(),
+            });
+        }
+    }
+
+    differences
+}
+
+fn diff_changes(
+    differences: &mut Vec<ChangelogDifference>,
+    section: ChangelogSection,
+    old: &Changes,
+    new: &Changes,
+) {
+    let mut removed = entries(old);
+    let mut added = vec![];
+    for entry in entries(new) {
+        // Duplicated entries are matched one for one.
+        match removed.iter().position(|old_entry| *old_entry == entry) {
+            Some(index) => {
+                removed.remove(index);
+            }
+            None => added.push(entry),
+        }
+    }
+    for (kind, text) in removed {
+        differences.push(ChangelogDifference::EntryRemoved {
+            section: section.clone(),
+            kind,
+            text,
+        });
+    }
+    for (kind, text) in added {
+        differences.push(ChangelogDifference::EntryAdded {
+            section: section.clone(),
+            kind,
+            text,
+        });
+    }
+}
+
+fn entries(changes: &Changes) -> Vec<(ChangeSetKind, String)> {
+    changes
+        .iter()
+        .flat_map(|change_set| {
+            change_set
+                .items()
+                .iter()
+                .map(move |change| (change_set.kind(), change.text().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const OLD: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Some `new` bull.
+- The other bull.
+
+## [0.2.0] - 2026-02-04
+
+### Removed
+
+- The bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    mod diff {
+        use super::*;
+
+        #[test]
+        fn should_report_nothing_for_identical_changelogs() {
+            let old = Changelog::parse(OLD).unwrap();
+            let new = Changelog::parse(OLD).unwrap();
+            assert_eq!(diff(&old, &new), vec![]);
+        }
+
+        #[test]
+        fn should_report_a_new_release() {
+            let old = Changelog::parse(OLD).unwrap();
+            let new = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- The other bull.
+
+## [0.3.0] - 2026-03-01
+
+### Added
+
+- Some `new` bull.
+
+## [0.2.0] - 2026-02-04
+
+### Removed
+
+- The bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.3.0...HEAD
+[0.3.0]: https://github.com/owner/repo/compare/v0.2.0...v0.3.0
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+            )
+            .unwrap();
+            let differences = diff(&old, &new);
+            assert_eq!(
+                differences,
+                vec![
+                    ChangelogDifference::EntryRemoved {
+                        section: ChangelogSection::Unreleased,
+                        kind: ChangeSetKind::Added,
+                        text: "Some `new` bull.".to_string(),
+                    },
+                    ChangelogDifference::ReleaseAdded(Version::new(0, 3, 0)),
+                    ChangelogDifference::ReferenceDefinitionChanged {
+                        label: "Unreleased".to_string(),
+                        old: "https://github.com/owner/repo/compare/v0.2.0...HEAD".to_string(),
+                        new: "https://github.com/owner/repo/compare/v0.3.0...HEAD".to_string(),
+                    },
+                    ChangelogDifference::ReferenceDefinitionAdded {
+                        label: "0.3.0".to_string(),
+                        dest: "https://github.com/owner/repo/compare/v0.2.0...v0.3.0".to_string(),
+                    },
+                ]
+            );
+            assert_eq!(
+                differences
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+                vec![
+                    "- Unreleased Added: Some `new` bull.",
+                    "+ release 0.3.0",
+                    "~ [Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD -> https://github.com/owner/repo/compare/v0.3.0...HEAD",
+                    "+ [0.3.0]: https://github.com/owner/repo/compare/v0.2.0...v0.3.0",
+                ]
+            );
+        }
+
+        #[test]
+        fn should_report_modified_releases() {
+            let old = Changelog::parse(OLD).unwrap();
+            let new = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Some `new` bull.
+- The other bull.
+
+## [0.2.0] - 2026-02-05 [YANKED]
+
+### Removed
+
+- The bull.
+
+### Fixed
+
+- The fix.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/releases/tag/v0.2.0
+",
+            )
+            .unwrap();
+            assert_eq!(
+                diff(&old, &new),
+                vec![
+                    ChangelogDifference::DateChanged {
+                        version: Version::new(0, 2, 0),
+                        old: NaiveDate::from_ymd_opt(2026, 2, 4).unwrap(),
+                        new: NaiveDate::from_ymd_opt(2026, 2, 5).unwrap(),
+                    },
+                    ChangelogDifference::YankedChanged {
+                        version: Version::new(0, 2, 0),
+                        yanked: true,
+                    },
+                    ChangelogDifference::EntryAdded {
+                        section: ChangelogSection::Release(Version::new(0, 2, 0)),
+                        kind: ChangeSetKind::Fixed,
+                        text: "The fix.".to_string(),
+                    },
+                    ChangelogDifference::ReleaseRemoved(Version::new(0, 1, 0)),
+                    ChangelogDifference::ReferenceDefinitionChanged {
+                        label: "0.2.0".to_string(),
+                        old: "https://github.com/owner/repo/compare/v0.1.0...v0.2.0".to_string(),
+                        new: "https://github.com/owner/repo/releases/tag/v0.2.0".to_string(),
+                    },
+                    ChangelogDifference::ReferenceDefinitionRemoved {
+                        label: "0.1.0".to_string(),
+                        dest: "https://github.com/owner/repo/releases/tag/v0.1.0".to_string(),
+                    },
+                ]
+            );
+        }
+    }
+}
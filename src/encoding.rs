@@ -0,0 +1,269 @@
+use std::{error::Error, fmt::Display, path::Path};
+
+/// The character encodings changelogs are read from, and written back in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    /// Only used when asked for, as any byte sequence is valid Latin-1.
+    Latin1,
+}
+
+/// The line ending style of a text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    Crlf,
+}
+
+impl LineEnding {
+    /// Detects the style from the first line ending of the text.
+    pub fn detect(text: &str) -> Self {
+        match text.find('\n') {
+            Some(index) if text[..index].ends_with('\r') => LineEnding::Crlf,
+            _ => LineEnding::Lf,
+        }
+    }
+
+    /// Converts all the line endings of the text to this style.
+    pub fn apply(&self, text: &str) -> String {
+        let text = text.replace("\r\n", "\n");
+        match self {
+            LineEnding::Lf => text,
+            LineEnding::Crlf => text.replace('\n', "\r\n"),
+        }
+    }
+}
+
+/// A text decoded from a file, along with what it takes to write it back the same way.
+///
+/// The byte order mark is stripped from the text, but line endings are left as is. The
+/// parser handles both styles, so ranges point at the lines of the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedText {
+    text: String,
+    encoding: TextEncoding,
+    bom: bool,
+    line_ending: LineEnding,
+}
+
+impl DecodedText {
+    /// Decodes UTF-8 and UTF-16, with or without byte order mark. When asked for, text that
+    /// isn't valid UTF-8 is decoded as Latin-1 instead of erroring.
+    pub fn decode(bytes: &[u8], latin1: bool) -> Result<Self, DecodeError> {
+        let (encoding, bom, content) = if let Some(content) = bytes.strip_prefix(b"\xEF\xBB\xBF") {
+            (TextEncoding::Utf8, true, content)
+        } else if let Some(content) = bytes.strip_prefix(b"\xFF\xFE") {
+            (TextEncoding::Utf16Le, true, content)
+        } else if let Some(content) = bytes.strip_prefix(b"\xFE\xFF") {
+            (TextEncoding::Utf16Be, true, content)
+        } else {
+            // Without byte order mark, UTF-16 gives itself away by the null half of the
+            // first ASCII character.
+            let encoding = match bytes {
+                [first, 0, ..] if *first != 0 => TextEncoding::Utf16Le,
+                [0, second, ..] if *second != 0 => TextEncoding::Utf16Be,
+                _ => TextEncoding::Utf8,
+            };
+            (encoding, false, bytes)
+        };
+
+        let (encoding, text) = match encoding {
+            TextEncoding::Utf8 => match String::from_utf8(content.to_vec()) {
+                Ok(text) => (encoding, text),
+                Err(_) if latin1 && !bom => (
+                    TextEncoding::Latin1,
+                    content.iter().map(|byte| char::from(*byte)).collect(),
+                ),
+                Err(err) => return Err(DecodeError::InvalidUtf8(err.utf8_error())),
+            },
+            TextEncoding::Utf16Le | TextEncoding::Utf16Be => {
+                if content.len() % 2 != 0 {
+                    return Err(DecodeError::InvalidUtf16);
+                }
+                let units = content.chunks_exact(2).map(|pair| match encoding {
+                    TextEncoding::Utf16Le => u16::from_le_bytes([pair[0], pair[1]]),
+                    _ => u16::from_be_bytes([pair[0], pair[1]]),
+                });
+                let text = char::decode_utf16(units)
+                    .collect::<Result<String, _>>()
+                    .map_err(|_| DecodeError::InvalidUtf16)?;
+                (encoding, text)
+            }
+            TextEncoding::Latin1 => unreachable!(),
+        };
+        let line_ending = LineEnding::detect(&text);
+        Ok(Self {
+            text,
+            encoding,
+            bom,
+            line_ending,
+        })
+    }
+
+    /// Reads and decodes a file.
+    pub fn read(path: &Path, latin1: bool) -> Result<Self, DecodeError> {
+        Self::decode(&std::fs::read(path)?, latin1)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    pub fn encoding(&self) -> TextEncoding {
+        self.encoding
+    }
+
+    pub fn has_bom(&self) -> bool {
+        self.bom
+    }
+
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Encodes a new text the way this one was, with the same encoding, byte order mark
+    /// and line ending style.
+    ///
+    /// Characters Latin-1 can't represent are replaced with question marks.
+    pub fn encode(&self, text: &str) -> Vec<u8> {
+        let text = self.line_ending.apply(text);
+        let mut bytes = vec![];
+        match self.encoding {
+            TextEncoding::Utf8 => {
+                if self.bom {
+                    bytes.extend_from_slice(b"\xEF\xBB\xBF");
+                }
+                bytes.extend_from_slice(text.as_bytes());
+            }
+            TextEncoding::Utf16Le => {
+                if self.bom {
+                    bytes.extend_from_slice(b"\xFF\xFE");
+                }
+                bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+            }
+            TextEncoding::Utf16Be => {
+                if self.bom {
+                    bytes.extend_from_slice(b"\xFE\xFF");
+                }
+                bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes));
+            }
+            TextEncoding::Latin1 => {
+                bytes.extend(text.chars().map(|c| u8::try_from(c).unwrap_or(b'?')));
+            }
+        }
+        bytes
+    }
+
+    /// Writes a new text to a file, [encoded](DecodedText::encode) the way this one was.
+    pub fn write(&self, path: &Path, text: &str) -> std::io::Result<()> {
+        std::fs::write(path, self.encode(text))
+    }
+}
+
+#[derive(Debug)]
+pub enum DecodeError {
+    Io(std::io::Error),
+    InvalidUtf8(std::str::Utf8Error),
+    InvalidUtf16,
+}
+
+impl From<std::io::Error> for DecodeError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::Io(err) => write!(f, "unable to read file: {}", err),
+            DecodeError::InvalidUtf8(err) => {
+                write!(f, "invalid UTF-8, consider decoding it as Latin-1: {}", err)
+            }
+            DecodeError::InvalidUtf16 => write!(f, "invalid UTF-16"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod decode {
+        use super::*;
+
+        #[test]
+        fn should_strip_utf8_bom() {
+            let decoded = DecodedText::decode(b"\xEF\xBB\xBF# Changelog\n", false).unwrap();
+            assert_eq!(decoded.text(), "# Changelog\n");
+            assert_eq!(decoded.encoding(), TextEncoding::Utf8);
+            assert!(decoded.has_bom());
+        }
+
+        #[test]
+        fn should_decode_utf16_with_or_without_bom() {
+            let le: Vec<u8> = "# Été\r\n"
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect();
+            let decoded =
+                DecodedText::decode(&[b"\xFF\xFE", le.as_slice()].concat(), false).unwrap();
+            assert_eq!(decoded.text(), "# Été\r\n");
+            assert_eq!(decoded.line_ending(), LineEnding::Crlf);
+
+            let be: Vec<u8> = "# Été\n"
+                .encode_utf16()
+                .flat_map(u16::to_be_bytes)
+                .collect();
+            let decoded = DecodedText::decode(&be, false).unwrap();
+            assert_eq!(decoded.text(), "# Été\n");
+            assert_eq!(decoded.encoding(), TextEncoding::Utf16Be);
+            assert!(!decoded.has_bom());
+        }
+
+        #[test]
+        fn should_fall_back_to_latin1_when_asked() {
+            assert!(matches!(
+                DecodedText::decode(b"# \xC9t\xE9\n", false),
+                Err(DecodeError::InvalidUtf8(_))
+            ));
+            let decoded = DecodedText::decode(b"# \xC9t\xE9\n", true).unwrap();
+            assert_eq!(decoded.text(), "# Été\n");
+            assert_eq!(decoded.encoding(), TextEncoding::Latin1);
+        }
+    }
+
+    mod encode {
+        use super::*;
+
+        #[test]
+        fn should_round_trip() {
+            for bytes in [
+                b"\xEF\xBB\xBF# Changelog\r\n\r\nIntro.\r\n".to_vec(),
+                b"\xFF\xFE#\x00 \x00\xE9\x00\r\x00\n\x00".to_vec(),
+                b"# \xC9t\xE9\n".to_vec(),
+            ] {
+                let decoded = DecodedText::decode(&bytes, true).unwrap();
+                assert_eq!(decoded.encode(decoded.text()), bytes);
+            }
+        }
+
+        #[test]
+        fn should_preserve_line_endings() {
+            let decoded = DecodedText::decode(b"# Changelog\r\n", false).unwrap();
+            assert_eq!(
+                decoded.encode("# Changelog\n\nIntro.\n"),
+                b"# Changelog\r\n\r\nIntro.\r\n"
+            );
+        }
+    }
+}
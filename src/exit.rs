@@ -0,0 +1,133 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::IsTerminal,
+    process::{ExitCode, Termination},
+};
+
+use miette::{IntoDiagnostic, Report, miette};
+
+/// The statuses the program exits with, documented in its help.
+///
+/// Failures are ordered by severity, so that a command failing in several ways exits with
+/// the most severe one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExitStatus {
+    Success = 0,
+    /// A changelog is well-formed, but fails the lints or the pull request policy.
+    LintFailure = 1,
+    /// The arguments are invalid.
+    UsageError = 2,
+    /// A changelog, or a fragment, can't be parsed.
+    ParseFailure = 3,
+    /// A file can't be read, decoded or written.
+    IoFailure = 4,
+    /// Anything else, such as a failing git command or a merge conflict.
+    Failure = 5,
+}
+
+impl ExitStatus {
+    pub const HELP: &str = "Exit status:
+  0  Success.
+  1  A changelog fails the lints or the pull request policy.
+  2  The arguments are invalid.
+  3  A changelog or a fragment can't be parsed.
+  4  A file can't be read, decoded or written.
+  5  Any other failure, such as a failing git command or a merge conflict.";
+}
+
+impl Termination for ExitStatus {
+    fn report(self) -> ExitCode {
+        ExitCode::from(self as u8)
+    }
+}
+
+/// A failed command, along with the status the program exits with.
+#[derive(Debug)]
+pub struct Failure {
+    status: ExitStatus,
+    report: Report,
+}
+
+impl Failure {
+    pub fn new(status: ExitStatus, report: Report) -> Self {
+        Self { status, report }
+    }
+
+    pub fn status(&self) -> ExitStatus {
+        self.status
+    }
+
+    pub fn report(&self) -> &Report {
+        &self.report
+    }
+
+    /// Prefixes the message, typically with the file the failure is about.
+    pub fn prefixed<D: Display>(self, prefix: D) -> Self {
+        Self::new(self.status, miette!("{}: {}", prefix, self.report))
+    }
+}
+
+/// Unclassified failures exit with [ExitStatus::Failure].
+impl From<Report> for Failure {
+    fn from(value: Report) -> Self {
+        Self::new(ExitStatus::Failure, value)
+    }
+}
+
+/// Classifies the error of a result by the status the program should exit with.
+pub trait ExitWith<T> {
+    fn exit_with(self, status: ExitStatus) -> Result<T, Failure>;
+}
+
+impl<T, E: Error + Send + Sync + 'static> ExitWith<T> for Result<T, E> {
+    fn exit_with(self, status: ExitStatus) -> Result<T, Failure> {
+        self.into_diagnostic()
+            .map_err(|report| Failure::new(status, report))
+    }
+}
+
+/// When to color the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Only on terminals, unless the `NO_COLOR` environment variable is set.
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to color what is written to the stream.
+    pub fn enabled<S: IsTerminal>(&self, stream: &S) -> bool {
+        match self {
+            ColorChoice::Auto => {
+                stream.is_terminal()
+                    && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+impl TryFrom<&str> for ColorChoice {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Paints a label in bold red or green, when colored.
+pub fn paint(label: &str, success: bool, colored: bool) -> String {
+    match (colored, success) {
+        (false, _) => label.to_string(),
+        (true, true) => format!("\x1b[1;32m{}\x1b[0m", label),
+        (true, false) => format!("\x1b[1;31m{}\x1b[0m", label),
+    }
+}
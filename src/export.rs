@@ -0,0 +1,173 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{
+    Changelog, DocumentFormat, ImportError, ReferenceDefinition, Release, Unreleased, check,
+    parse::Title,
+};
+
+impl<'source> Changelog<'source> {
+    /// Serializes the parsed changelog in the given format.
+    ///
+    /// The output contains the text of every entry alongside the source ranges
+    /// of the parsed elements, so consumers don't need the original markdown.
+    pub fn export(&self, format: DocumentFormat) -> Result<String, ExportError> {
+        match format {
+            DocumentFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            DocumentFormat::Yaml => Ok(serde_yaml::to_string(self)?),
+        }
+    }
+}
+
+// The content of an export, without the source its ranges point into.
+#[derive(serde::Deserialize)]
+struct Export {
+    title: Title,
+    unreleased: Option<Unreleased>,
+    releases: Vec<Release>,
+    reference_definitions: Vec<ReferenceDefinition<'static>>,
+}
+
+/// Generates the markdown of a changelog exported by [Changelog::export] in the given format.
+///
+/// The ranges of the export point into its original markdown, which the export doesn't hold.
+/// So the changelog is rendered again, to be parsed back with ranges that match the markdown.
+pub fn import_export(content: &str, format: DocumentFormat) -> Result<String, ImportError> {
+    let export: Export = match format {
+        DocumentFormat::Json => serde_json::from_str(content)?,
+        DocumentFormat::Yaml => serde_yaml::from_str(content)?,
+    };
+    let markdown = Changelog::new(
+        "",
+        export.title,
+        export.unreleased,
+        export.releases,
+        export.reference_definitions,
+    )
+    .to_markdown();
+    check(&markdown)?;
+    Ok(markdown)
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    Json(serde_json::Error),
+    Yaml(serde_yaml::Error),
+}
+
+impl From<serde_json::Error> for ExportError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl From<serde_yaml::Error> for ExportError {
+    fn from(value: serde_yaml::Error) -> Self {
+        Self::Yaml(value)
+    }
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::Json(err) => write!(f, "error while exporting to json: {}", err),
+            ExportError::Yaml(err) => write!(f, "error while exporting to yaml: {}", err),
+        }
+    }
+}
+
+impl Error for ExportError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CHANGELOG: &str = r"# Changelog
+
+This is a mfking changelog y'all.
+
+## [0.2.0] - 2026-02-04 [YANKED]
+
+### Removed
+
+- The `bull`.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0";
+
+    mod export {
+        use super::*;
+
+        #[test]
+        fn should_export_to_json() {
+            let changelog = Changelog::parse(CHANGELOG).unwrap();
+            let exported = changelog.export(DocumentFormat::Json).unwrap();
+            let value: serde_json::Value = serde_json::from_str(&exported).unwrap();
+            let release = &value["releases"][0];
+            assert_eq!(release["version"], "0.2.0");
+            assert_eq!(release["date"], "2026-02-04");
+            assert_eq!(release["yanked"], true);
+            assert_eq!(release["changes"][0]["kind"], "removed");
+            assert_eq!(release["changes"][0]["items"][0]["text"], "The `bull`.");
+            assert_eq!(
+                release["changes"][0]["items"][0]["range"],
+                serde_json::json!({"start": 95, "end": 110})
+            );
+            assert_eq!(value["reference_definitions"][1]["label"], "0.1.0");
+            assert_eq!(
+                value["reference_definitions"][1]["dest"],
+                "https://github.com/owner/repo/releases/tag/v0.1.0"
+            );
+        }
+
+        #[test]
+        fn should_export_to_yaml() {
+            let changelog = Changelog::parse(CHANGELOG).unwrap();
+            let exported = changelog.export(DocumentFormat::Yaml).unwrap();
+            let value: serde_yaml::Value = serde_yaml::from_str(&exported).unwrap();
+            let release = &value["releases"][1];
+            assert_eq!(release["version"], "0.1.0");
+            assert_eq!(release["date"], "2026-01-01");
+            assert_eq!(release["yanked"], false);
+            assert_eq!(release["changes"][0]["kind"], "added");
+            assert_eq!(release["changes"][0]["items"][0]["text"], "Some bull.");
+        }
+    }
+
+    mod import_export {
+        use super::*;
+
+        #[test]
+        fn should_round_trip() {
+            let changelog = Changelog::parse(CHANGELOG).unwrap();
+            for format in [DocumentFormat::Json, DocumentFormat::Yaml] {
+                let exported = changelog.export(format).unwrap();
+                let markdown = import_export(&exported, format).unwrap();
+                assert_eq!(markdown, format!("{}\n", CHANGELOG));
+                let imported = Changelog::parse(&markdown).unwrap();
+                assert_eq!(imported.releases(), changelog.releases());
+                assert_eq!(imported.export(format).unwrap(), exported);
+            }
+        }
+
+        #[test]
+        fn should_match_ranges_to_reformatted_markdown() {
+            let source = CHANGELOG.replace("\n- Some", "\n\n\n* Some");
+            let changelog = Changelog::parse(&source).unwrap();
+            let exported = changelog.export(DocumentFormat::Json).unwrap();
+            let markdown = import_export(&exported, DocumentFormat::Json).unwrap();
+            let imported = Changelog::parse(&markdown).unwrap();
+            let [.., release] = imported.releases() else {
+                panic!("{:?}", imported.releases());
+            };
+            let change = &release.changes().iter().next().unwrap().items()[0];
+            assert_eq!(markdown[change.range().clone()].trim_end(), "- Some bull.");
+            assert_eq!(markdown, format!("{}\n", CHANGELOG));
+        }
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    ChangeSetKind, Changelog,
+    merge::{reference_definitions, unreleased_entries, unreleased_section},
+};
+
+/// The directory fragments are expected in by default, relative to the changelog.
+pub const DEFAULT_FRAGMENTS_DIRECTORY: &str = "changelog.d";
+
+/// A changelog fragment, holding entries to add to the unreleased section.
+///
+/// Fragments are markdown files named `<id>.<kind>.md`, where the id is anything unique, like
+/// an issue number, and the kind is the lowercase name of a change set kind. For example,
+/// `1234.fixed.md`. The content is either a single entry, or a `-` list of entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragment {
+    path: PathBuf,
+    kind: ChangeSetKind,
+    entries: Vec<String>,
+}
+
+impl Fragment {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn kind(&self) -> ChangeSetKind {
+        self.kind
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    pub fn parse(path: PathBuf, content: &str) -> Result<Self, FragmentError> {
+        let Some(kind) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".md"))
+            .and_then(|name| name.rsplit_once('.'))
+            .filter(|(id, _)| !id.is_empty())
+            .and_then(|(_, kind)| parse_kind(kind))
+        else {
+            return Err(FragmentError::InvalidFileName(path));
+        };
+
+        let content = content.trim();
+        if content.is_empty() {
+            return Err(FragmentError::EmptyContent(path));
+        }
+        if content.lines().any(|line| line.starts_with('#')) {
+            return Err(FragmentError::InvalidContent(path));
+        }
+
+        let entries = if content.starts_with("- ") {
+            // Every unindented line starts a new entry, the others continue the current one.
+            let mut entries: Vec<String> = vec![];
+            for line in content.lines() {
+                if let Some(entry) = line.strip_prefix("- ") {
+                    entries.push(entry.trim().to_string());
+                } else if line.trim().is_empty() {
+                    continue;
+                } else if line.starts_with(' ') {
+                    let entry = entries.last_mut().unwrap();
+                    entry.push('\n');
+                    entry.push_str(line.trim_end());
+                } else {
+                    return Err(FragmentError::InvalidContent(path));
+                }
+            }
+            entries
+        } else {
+            vec![content.to_string()]
+        };
+        Ok(Self {
+            path,
+            kind,
+            entries,
+        })
+    }
+}
+
+fn parse_kind(kind: &str) -> Option<ChangeSetKind> {
+    [
+        ChangeSetKind::Added,
+        ChangeSetKind::Changed,
+        ChangeSetKind::Deprecated,
+        ChangeSetKind::Fixed,
+        ChangeSetKind::Removed,
+        ChangeSetKind::Security,
+    ]
+    .into_iter()
+    .find(|candidate| candidate.to_string().to_lowercase() == kind)
+}
+
+/// Reads and validates all the fragments of a directory, sorted by file name.
+///
+/// Hidden files, like a `.gitkeep`, are ignored, as well as subdirectories and files that are
+/// not markdown. A missing directory holds no fragments.
+pub fn read_fragments(directory: &Path) -> Result<Vec<Fragment>, FragmentError> {
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err.into()),
+    };
+    let mut paths = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        let markdown = path.extension().is_some_and(|extension| extension == "md");
+        if !hidden && markdown && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut fragments = vec![];
+    for path in paths {
+        let content = std::fs::read_to_string(&path)?;
+        fragments.push(Fragment::parse(path, &content)?);
+    }
+    Ok(fragments)
+}
+
+impl<'source> Changelog<'source> {
+    /// Renders the changelog with the entries of the fragments added to its unreleased section.
+    ///
+    /// The entries are appended to the existing ones of their change set kind, in the order of
+    /// the fragments. The change sets remain in canonical order.
+    pub fn collect_fragments(&self, fragments: &[Fragment]) -> String {
+        self.with_unreleased_entries(fragments.iter().flat_map(|fragment| {
+            fragment
+                .entries()
+                .iter()
+                .map(|entry| (fragment.kind(), entry.clone()))
+        }))
+    }
+
+    /// Renders the changelog with the given entries appended to its unreleased section.
+    ///
+    /// The unreleased section, and its reference definition, are created when missing. An
+    /// existing one is kept even without entries.
+    pub fn with_unreleased_entries(
+        &self,
+        new_entries: impl IntoIterator<Item = (ChangeSetKind, String)>,
+    ) -> String {
+        let mut entries = unreleased_entries(self);
+        for (kind, entry) in new_entries {
+            entries.entry(kind).or_default().push(entry);
+        }
+        let unreleased = unreleased_section(
+            entries,
+            self.unreleased().is_some() || self.releases().is_empty(),
+        );
+        let reference_definitions = reference_definitions(self, unreleased.is_some());
+        Changelog::new(
+            "",
+            self.title().clone(),
+            unreleased,
+            self.releases().to_vec(),
+            reference_definitions,
+        )
+        .to_markdown()
+    }
+}
+
+#[derive(Debug)]
+pub enum FragmentError {
+    Io(std::io::Error),
+    InvalidFileName(PathBuf),
+    EmptyContent(PathBuf),
+    InvalidContent(PathBuf),
+}
+
+impl From<std::io::Error> for FragmentError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Display for FragmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FragmentError::Io(err) => write!(f, "unable to read fragments: {}", err),
+            FragmentError::InvalidFileName(path) => write!(
+                f,
+                "expected fragment {} to be named <id>.<kind>.md, with kind one of added, changed, deprecated, fixed, removed or security",
+                path.display()
+            ),
+            FragmentError::EmptyContent(path) => {
+                write!(f, "fragment {} is empty", path.display())
+            }
+            FragmentError::InvalidContent(path) => write!(
+                f,
+                "expected fragment {} to hold a single entry or a list of entries, without headings",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for FragmentError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ChangelogDocument;
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn should_parse_single_entry() {
+            let fragment =
+                Fragment::parse("changelog.d/1234.fixed.md".into(), "The `bull`.\n").unwrap();
+            assert_eq!(fragment.kind(), ChangeSetKind::Fixed);
+            assert_eq!(fragment.entries(), ["The `bull`."]);
+        }
+
+        #[test]
+        fn should_parse_list_of_entries() {
+            let fragment = Fragment::parse(
+                "changelog.d/some.feature.added.md".into(),
+                "- Some bull.\n- More bull,\n  on two lines.\n",
+            )
+            .unwrap();
+            assert_eq!(fragment.kind(), ChangeSetKind::Added);
+            assert_eq!(
+                fragment.entries(),
+                ["Some bull.", "More bull,\n  on two lines."]
+            );
+        }
+
+        #[test]
+        fn should_error_with_unknown_kind() {
+            let result = Fragment::parse("changelog.d/1234.fuckulated.md".into(), "Bull.");
+            assert!(
+                matches!(result, Err(FragmentError::InvalidFileName(_))),
+                "{:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn should_error_without_id() {
+            let result = Fragment::parse("changelog.d/fixed.md".into(), "Bull.");
+            assert!(
+                matches!(result, Err(FragmentError::InvalidFileName(_))),
+                "{:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn should_error_with_empty_content() {
+            let result = Fragment::parse("changelog.d/1234.fixed.md".into(), "\n  \n");
+            assert!(
+                matches!(result, Err(FragmentError::EmptyContent(_))),
+                "{:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn should_error_with_heading() {
+            let result = Fragment::parse("changelog.d/1234.fixed.md".into(), "### Fixed\n- Bull.");
+            assert!(
+                matches!(result, Err(FragmentError::InvalidContent(_))),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    mod read_fragments {
+        use super::*;
+
+        #[test]
+        fn should_only_read_markdown_files() {
+            let root = std::env::temp_dir().join("changelog-fragments-markdown");
+            let _ = std::fs::remove_dir_all(&root);
+            std::fs::create_dir_all(root.join(".keep")).unwrap();
+            std::fs::create_dir_all(root.join("1.added.md")).unwrap();
+            std::fs::write(root.join(".gitkeep"), "").unwrap();
+            std::fs::write(root.join("README.txt"), "Fragments go here.").unwrap();
+            std::fs::write(root.join("2.fixed.md"), "The bull.").unwrap();
+
+            let fragments = read_fragments(&root).unwrap();
+            assert_eq!(
+                fragments,
+                vec![Fragment::parse(root.join("2.fixed.md"), "The bull.").unwrap()]
+            );
+        }
+
+        #[test]
+        fn should_read_nothing_from_missing_directory() {
+            let root = std::env::temp_dir().join("changelog-fragments-missing");
+            let _ = std::fs::remove_dir_all(&root);
+            assert_eq!(read_fragments(&root).unwrap(), vec![]);
+        }
+    }
+
+    mod collect_fragments {
+        use super::*;
+
+        #[test]
+        fn should_add_entries_to_unreleased() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Fixed
+
+- The bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+            )
+            .unwrap();
+            let fragments = [
+                Fragment::parse("changelog.d/1.fixed.md".into(), "The other bull.").unwrap(),
+                Fragment::parse(
+                    "changelog.d/2.added.md".into(),
+                    "- New bull.\n- Newer bull.",
+                )
+                .unwrap(),
+            ];
+            assert_eq!(
+                changelog.collect_fragments(&fragments),
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- New bull.
+- Newer bull.
+
+### Fixed
+
+- The bull.
+- The other bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+"
+            );
+        }
+
+        #[test]
+        fn should_create_unreleased_section() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+            )
+            .unwrap();
+            let fragments =
+                [Fragment::parse("changelog.d/1.removed.md".into(), "The bull.").unwrap()];
+            let markdown = changelog.collect_fragments(&fragments);
+            assert!(markdown.contains("## [Unreleased]\n\n### Removed\n\n- The bull.\n"));
+            assert!(markdown.contains(
+                "[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD\n[0.1.0]"
+            ));
+        }
+
+        #[test]
+        fn should_keep_empty_unreleased_section_of_new_changelog() {
+            let source = ChangelogDocument::new("https://github.com/owner/repo".to_string())
+                .to_markdown()
+                .unwrap();
+            let changelog = Changelog::parse(&source).unwrap();
+            let collected = changelog.collect_fragments(&[]);
+            assert_eq!(collected, source);
+            assert_eq!(crate::check(&collected).map(|_| ()), Ok(()));
+
+            let fragments =
+                [Fragment::parse("changelog.d/1.added.md".into(), "New bull.").unwrap()];
+            let collected = changelog.collect_fragments(&fragments);
+            assert!(collected.contains("## [Unreleased]\n\n### Added\n\n- New bull.\n"));
+            assert_eq!(crate::check(&collected).map(|_| ()), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use chrono::NaiveDate;
+use semver::Version;
+
+/// How the releases of a changelog are tagged in git.
+///
+/// In a monorepo, the tags of a package are prefixed with its name to tell them apart from
+/// the tags of the other packages.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TagScheme {
+    /// Tags like `v1.2.3`.
+    #[default]
+    Plain,
+    /// Tags like `package@1.2.3`.
+    PackageAt(String),
+    /// Tags like `package-v1.2.3`.
+    PackageV(String),
+}
+
+impl TagScheme {
+    /// Detects the scheme of an existing tag, given the label of the release it tags.
+    pub fn detect(tag: &str, label: &str) -> Option<Self> {
+        let prefix = tag.strip_suffix(label)?;
+        if prefix == "v" {
+            Some(TagScheme::Plain)
+        } else if let Some(package) = prefix.strip_suffix('@').filter(|p| !p.is_empty()) {
+            Some(TagScheme::PackageAt(package.to_string()))
+        } else {
+            let package = prefix.strip_suffix("-v").filter(|p| !p.is_empty())?;
+            Some(TagScheme::PackageV(package.to_string()))
+        }
+    }
+
+    /// The package the tags are prefixed with, if any.
+    pub fn package(&self) -> Option<&str> {
+        match self {
+            TagScheme::Plain => None,
+            TagScheme::PackageAt(package) | TagScheme::PackageV(package) => Some(package),
+        }
+    }
+
+    /// The name of the tag of the release with the given label.
+    pub fn tag(&self, label: &str) -> String {
+        match self {
+            TagScheme::Plain => format!("v{}", label),
+            TagScheme::PackageAt(package) => format!("{}@{}", package, label),
+            TagScheme::PackageV(package) => format!("{}-v{}", package, label),
+        }
+    }
+
+    /// The name of the git tag of a release.
+    pub fn release_tag(&self, version: &Version) -> String {
+        self.tag(&version.to_string())
+    }
+
+    /// The version a tag following the scheme points to. Other tags have none.
+    pub fn version(&self, tag: &str) -> Option<Version> {
+        let version = match self {
+            TagScheme::Plain => tag.strip_prefix('v'),
+            TagScheme::PackageAt(package) => tag
+                .strip_prefix(package.as_str())
+                .and_then(|rest| rest.strip_prefix('@')),
+            TagScheme::PackageV(package) => tag
+                .strip_prefix(package.as_str())
+                .and_then(|rest| rest.strip_prefix("-v")),
+        }?;
+        Version::parse(version).ok()
+    }
+}
+
+/// Reads the content of a file at the given revision of the enclosing git repository.
+///
+/// The content is returned as is, to be decoded like the file of the working tree. The path is interpreted relative to the current directory, like any other path passed
+/// on the command line, rather than relative to the root of the repository. Absolute paths
+/// are read from the repository enclosing them.
+pub fn read_revision(revision: &str, path: &Path) -> Result<Vec<u8>, GitError> {
+    if !path.is_absolute() {
+        return git_bytes(&["show", &format!("{}:./{}", revision, path.display())]);
+    }
+    let directory = path.parent().unwrap_or(path);
+    let root = git(&[
+        "-C",
+        &directory.to_string_lossy(),
+        "rev-parse",
+        "--show-toplevel",
+    ])?;
+    let root = PathBuf::from(root.trim());
+    // Symbolic links, like a temporary directory, are resolved the same way on both sides.
+    let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let path = canonical(path);
+    let relative = path
+        .strip_prefix(canonical(&root))
+        .map_err(|_| GitError::Failed(format!("{} is outside the repository", path.display())))?;
+    let object = format!("{}:{}", revision, relative.display());
+    git_bytes(&["-C", &root.to_string_lossy(), "show", &object])
+}
+
+/// Lists the full messages of the commits reachable from `HEAD`, from the oldest to the most
+/// recent. When a revision is given, only the commits since that revision are listed.
+pub fn commit_messages(since: Option<&str>) -> Result<Vec<String>, GitError> {
+    let range = match since {
+        Some(revision) => format!("{}..HEAD", revision),
+        None => "HEAD".to_string(),
+    };
+    let output = git(&["log", "--reverse", "--format=%B%x00", &range])?;
+    Ok(output
+        .split('\0')
+        .map(str::trim)
+        .filter(|message| !message.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// A tag of a git repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitTag {
+    name: String,
+    date: NaiveDate,
+}
+
+impl GitTag {
+    pub fn new(name: String, date: NaiveDate) -> Self {
+        Self { name, date }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The date of the tagged commit, rather than the date of the tag itself.
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+}
+
+/// Lists the tags of the git repository at the given path.
+pub fn git_tags(repository: &Path) -> Result<Vec<GitTag>, GitError> {
+    let repository = repository.to_string_lossy();
+    // Annotated tags only have a date once dereferenced to their commit, lightweight tags
+    // point to the commit directly.
+    let output = git(&[
+        "-C",
+        &repository,
+        "for-each-ref",
+        "refs/tags",
+        "--format=%(refname:short)%09%(committerdate:short)%09%(*committerdate:short)",
+    ])?;
+    let mut tags = vec![];
+    for line in output.lines() {
+        let mut fields = line.split('\t');
+        let name = fields.next().unwrap_or_default();
+        let date = fields
+            .filter(|date| !date.is_empty())
+            .find_map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+        // Tags of trees or blobs have no commit date, they can't be releases.
+        if let Some(date) = date {
+            tags.push(GitTag::new(name.to_string(), date));
+        }
+    }
+    Ok(tags)
+}
+
+/// The web URL of the repository enclosing the directory, inferred from the `origin` remote
+/// of its `.git/config`, or from its first remote without one. None outside of a repository
+/// or without remotes.
+pub fn remote_url(directory: &Path) -> std::io::Result<Option<String>> {
+    // Relative paths have no ancestors past their first component.
+    let directory = directory.canonicalize()?;
+    let Some(config) = directory
+        .ancestors()
+        .map(|ancestor| ancestor.join(".git").join("config"))
+        .find(|config| config.is_file())
+    else {
+        return Ok(None);
+    };
+    Ok(config_remote_url(&std::fs::read_to_string(config)?))
+}
+
+// The URL of the preferred remote of a git config file.
+fn config_remote_url(config: &str) -> Option<String> {
+    let mut remotes: Vec<(&str, &str)> = vec![];
+    let mut remote = None;
+    for line in config.lines().map(str::trim) {
+        if let Some(section) = line.strip_prefix('[') {
+            remote = section
+                .strip_prefix("remote \"")
+                .and_then(|section| section.strip_suffix("\"]"));
+        } else if let (Some(name), Some((key, value))) = (remote, line.split_once('='))
+            && key.trim() == "url"
+        {
+            remotes.push((name, value.trim()));
+        }
+    }
+    let (_, url) = remotes
+        .iter()
+        .find(|(name, _)| *name == "origin")
+        .or(remotes.first())?;
+    web_url(url)
+}
+
+// Converts the HTTPS and SSH URLs git accepts to the URL of the web page of the repository.
+fn web_url(url: &str) -> Option<String> {
+    let address = match url.split_once("://") {
+        Some(("https" | "http" | "ssh" | "git", address)) => address.to_string(),
+        Some(_) => return None,
+        // Like `git@github.com:owner/repo.git`, local paths have no colon.
+        None if url.contains(':') => url.replacen(':', "/", 1),
+        None => return None,
+    };
+    // Credentials and ports aren't part of the web URL.
+    let address = address
+        .rsplit_once('@')
+        .map_or(address.as_str(), |(_, address)| address);
+    let (host, path) = address.split_once('/')?;
+    let host = host.split(':').next().filter(|host| !host.is_empty())?;
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    Some(format!("https://{}/{}", host, path))
+}
+
+fn git(args: &[&str]) -> Result<String, GitError> {
+    String::from_utf8(git_bytes(args)?).map_err(|_| GitError::InvalidUtf8)
+}
+
+fn git_bytes(args: &[&str]) -> Result<Vec<u8>, GitError> {
+    let output = Command::new("git").args(args).output()?;
+    if !output.status.success() {
+        return Err(GitError::Failed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(output.stdout)
+}
+
+#[derive(Debug)]
+pub enum GitError {
+    Io(std::io::Error),
+    Failed(String),
+    InvalidUtf8,
+}
+
+impl From<std::io::Error> for GitError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Display for GitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitError::Io(err) => write!(f, "unable to run git: {}", err),
+            GitError::Failed(stderr) => write!(f, "git failed: {}", stderr),
+            GitError::InvalidUtf8 => write!(f, "git output is not valid UTF-8"),
+        }
+    }
+}
+
+impl Error for GitError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod tag_scheme {
+        use super::*;
+
+        #[test]
+        fn should_detect_schemes() {
+            assert_eq!(TagScheme::detect("v1.2.3", "1.2.3"), Some(TagScheme::Plain));
+            assert_eq!(
+                TagScheme::detect("bull@1.2.3", "1.2.3"),
+                Some(TagScheme::PackageAt("bull".to_string()))
+            );
+            assert_eq!(
+                TagScheme::detect("bull-v1.2.3", "1.2.3"),
+                Some(TagScheme::PackageV("bull".to_string()))
+            );
+            assert_eq!(TagScheme::detect("release-1.2.3", "1.2.3"), None);
+            assert_eq!(TagScheme::detect("v1.2.3", "1.2.4"), None);
+        }
+
+        #[test]
+        fn should_parse_versions_of_own_tags_only() {
+            let scheme = TagScheme::PackageAt("bull".to_string());
+            assert_eq!(scheme.version("bull@1.2.3"), Some(Version::new(1, 2, 3)));
+            assert_eq!(scheme.version("other@1.2.3"), None);
+            assert_eq!(scheme.version("v1.2.3"), None);
+            assert_eq!(
+                TagScheme::PackageV("bull".to_string()).release_tag(&Version::new(1, 2, 3)),
+                "bull-v1.2.3"
+            );
+        }
+    }
+
+    mod remote_url {
+        use super::*;
+
+        #[test]
+        fn should_prefer_origin() {
+            let config = r#"[core]
+	bare = false
+[remote "upstream"]
+	url = https://github.com/upstream/repo.git
+	fetch = +refs/heads/*:refs/remotes/upstream/*
+[remote "origin"]
+	url = git@github.com:owner/repo.git
+	fetch = +refs/heads/*:refs/remotes/origin/*
+"#;
+            assert_eq!(
+                config_remote_url(config),
+                Some("https://github.com/owner/repo".to_string())
+            );
+            assert_eq!(config_remote_url("[core]\n\tbare = false\n"), None);
+        }
+
+        #[test]
+        fn should_convert_remote_urls_to_web_urls() {
+            for url in [
+                "https://github.com/owner/repo.git",
+                "https://user@github.com/owner/repo/",
+                "ssh://git@github.com:22/owner/repo.git",
+                "git@github.com:owner/repo.git",
+            ] {
+                assert_eq!(
+                    web_url(url),
+                    Some("https://github.com/owner/repo".to_string())
+                );
+            }
+            assert_eq!(web_url("file:///srv/repo.git"), None);
+            assert_eq!(web_url("/srv/repo.git"), None);
+        }
+    }
+
+    mod read_revision {
+        use super::*;
+        use crate::DecodedText;
+
+        // Each test gets its own repository, as they run concurrently.
+        fn commit_changelog(test: &str, content: &[u8]) -> PathBuf {
+            let root = std::env::temp_dir().join(format!("changelog-git-{}", test));
+            let _ = std::fs::remove_dir_all(&root);
+            std::fs::create_dir_all(root.join("docs")).unwrap();
+            std::fs::write(root.join("docs/CHANGELOG.md"), content).unwrap();
+            let root_arg = root.to_string_lossy();
+            for args in [
+                vec!["init", "-q"],
+                vec!["add", "."],
+                vec![
+                    "-c",
+                    "user.name=Bull",
+                    "-c",
+                    "user.email=bull@example.com",
+                    "commit",
+                    "-qm",
+                    "Bull",
+                ],
+            ] {
+                git(&[&["-C", &root_arg], args.as_slice()].concat()).unwrap();
+            }
+            root
+        }
+
+        #[test]
+        fn should_read_absolute_paths_from_their_repository() {
+            let root = commit_changelog("read-revision", b"# Changelog\n");
+            std::fs::write(root.join("docs/CHANGELOG.md"), "# Edited\n").unwrap();
+            assert_eq!(
+                read_revision("HEAD", &root.join("docs/CHANGELOG.md")).unwrap(),
+                b"# Changelog\n"
+            );
+        }
+
+        #[test]
+        fn should_decode_like_the_working_tree() {
+            let utf16: Vec<u8> = [0xFEFF_u16]
+                .into_iter()
+                .chain("# Changelog\n".encode_utf16())
+                .flat_map(u16::to_le_bytes)
+                .collect();
+            let root = commit_changelog("read-revision-utf16", &utf16);
+            let path = root.join("docs/CHANGELOG.md");
+            let base = DecodedText::decode(&read_revision("HEAD", &path).unwrap(), false).unwrap();
+            let head = DecodedText::read(&path, false).unwrap();
+            assert_eq!(base, head);
+            assert_eq!(base.text(), "# Changelog\n");
+        }
+    }
+}
@@ -0,0 +1,529 @@
+use std::{collections::BTreeMap, error::Error, fmt::Display};
+
+use changelog_ast::CowStr;
+use chrono::NaiveDate;
+use semver::Version;
+use url::Url;
+
+#[cfg(feature = "serde")]
+use crate::DocumentFormat;
+use crate::{
+    Change, ChangeSet, ChangeSetKind, Changelog, ChangelogCheckError, Changes, DebianParseError,
+    KEEP_A_CHANGELOG_INTRO, ReferenceDefinition, Release, Unreleased, UnreleasedHeading, Yanked,
+    check,
+    lint::ref_def_linters::RefDefLinter,
+    parse::{Title, TitleHeading},
+};
+
+/// A structured description of a changelog, from which a Keep a Changelog file is generated.
+///
+/// Releases are expected from the most recent to the oldest, and their entries are grouped
+/// by change set kind. The reference definitions are generated from the repository URL.
+///
+/// ```yaml
+/// # Optional, the only accepted value is "Changelog".
+/// title: Changelog
+/// # Optional, defaults to the Keep a Changelog introduction.
+/// intro: All notable changes to this project will be documented in this file.
+/// repository: https://github.com/owner/repo
+/// # Optional.
+/// unreleased:
+///   added:
+///     - Something new.
+/// releases:
+///   - version: 0.2.0
+///     date: 2024-05-01
+///     # Optional, defaults to false.
+///     yanked: true
+///     changes:
+///       removed:
+///         - Something old.
+///   - version: 0.1.0
+///     date: 2024-04-01
+///     changes:
+///       added:
+///         - Something old.
+/// ```
+///
+/// The allowed change set kinds are `added`, `changed`, `deprecated`, `fixed`, `removed`
+/// and `security`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChangelogDocument {
+    #[cfg_attr(feature = "serde", serde(default = "default_title"))]
+    pub title: String,
+    #[cfg_attr(feature = "serde", serde(default = "default_intro"))]
+    pub intro: String,
+    pub repository: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub unreleased: BTreeMap<ChangeSetKind, Vec<String>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub releases: Vec<ReleaseDocument>,
+}
+
+/// A release of a [ChangelogDocument].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReleaseDocument {
+    pub version: Version,
+    pub date: NaiveDate,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub yanked: bool,
+    pub changes: BTreeMap<ChangeSetKind, Vec<String>>,
+}
+
+impl ChangelogDocument {
+    /// Creates a document with the default title and introduction, and no changes.
+    pub fn new(repository: String) -> Self {
+        Self {
+            title: default_title(),
+            intro: default_intro(),
+            repository,
+            unreleased: BTreeMap::new(),
+            releases: vec![],
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn parse(content: &str, format: DocumentFormat) -> Result<Self, ImportError> {
+        match format {
+            DocumentFormat::Json => Ok(serde_json::from_str(content)?),
+            DocumentFormat::Yaml => Ok(serde_yaml::from_str(content)?),
+        }
+    }
+}
+
+fn default_title() -> String {
+    "Changelog".to_string()
+}
+
+fn default_intro() -> String {
+    KEEP_A_CHANGELOG_INTRO.to_string()
+}
+
+/// Generates a Keep a Changelog markdown file from a [ChangelogDocument] in the given format.
+///
+/// The generated file is guaranteed to pass the checks of [check].
+#[cfg(feature = "serde")]
+pub fn import(content: &str, format: DocumentFormat) -> Result<String, ImportError> {
+    let document = ChangelogDocument::parse(content, format)?;
+    document.to_markdown()
+}
+
+impl ChangelogDocument {
+    /// Generates the Keep a Changelog markdown file described by this document.
+    ///
+    /// The generated file is guaranteed to pass the checks of [check].
+    pub fn to_markdown(self) -> Result<String, ImportError> {
+        let markdown = self.into_changelog()?.to_markdown();
+        check(&markdown)?;
+        Ok(markdown)
+    }
+}
+
+impl ChangelogDocument {
+    // The changelog has no source for its ranges to point into, so it is only ever rendered.
+    fn into_changelog(self) -> Result<Changelog<'static>, ImportError> {
+        if self.title != "Changelog" {
+            return Err(ImportError::InvalidTitle(self.title));
+        }
+        let linter = Url::parse(&self.repository)
+            .ok()
+            .and_then(|url| RefDefLinter::try_from_url(&url))
+            .ok_or_else(|| ImportError::UnsupportedRepository(self.repository.clone()))?;
+
+        // Without any release, the unreleased section is kept even empty, like in a new changelog.
+        let unreleased = if self.unreleased.values().all(Vec::is_empty) && !self.releases.is_empty()
+        {
+            None
+        } else {
+            Some(Unreleased::new(
+                UnreleasedHeading::new(0..0),
+                to_changes(self.unreleased),
+            ))
+        };
+
+        let mut releases = vec![];
+        for release in self.releases {
+            if release.changes.values().all(Vec::is_empty) {
+                return Err(ImportError::EmptyRelease(release.version));
+            }
+            releases.push(Release::new(
+                0..0,
+                release.version,
+                release.date,
+                to_changes(release.changes),
+                Yanked(release.yanked),
+            ));
+        }
+
+        let reference_definitions = reference_definitions(&linter, unreleased.is_some(), &releases);
+        let title = Title::new(TitleHeading::new(0..0), 0..0, self.intro.trim().to_string());
+        Ok(Changelog::new(
+            "",
+            title,
+            unreleased,
+            releases,
+            reference_definitions,
+        ))
+    }
+}
+
+pub(crate) fn to_changes(change_sets: BTreeMap<ChangeSetKind, Vec<String>>) -> Changes {
+    // The map iterates in the order of the kinds, which is the order expected in the changelog.
+    Changes::new(
+        change_sets
+            .into_iter()
+            .filter(|(_, entries)| !entries.is_empty())
+            .map(|(kind, entries)| {
+                let items = entries
+                    .into_iter()
+                    .map(|entry| Change::new(0..0, entry.trim().to_string()))
+                    .collect();
+                ChangeSet::from((kind, 0..0, items))
+            })
+            .collect(),
+    )
+}
+
+fn reference_definitions(
+    linter: &RefDefLinter,
+    has_unreleased: bool,
+    releases: &[Release],
+) -> Vec<ReferenceDefinition<'static>> {
+    let mut labels: Vec<String> = releases.iter().map(|r| r.version().to_string()).collect();
+    if has_unreleased {
+        labels.insert(0, "Unreleased".to_string());
+    }
+
+    let mut reference_definitions = vec![];
+    for (index, label) in labels.iter().enumerate() {
+        let dest = match labels.get(index + 1) {
+            Some(previous) => linter.diff_destination(previous, label),
+            None => linter.release_destination(label),
+        };
+        reference_definitions.push(ReferenceDefinition::new(
+            label.clone(),
+            CowStr::from(dest),
+            0..0,
+        ));
+    }
+    reference_definitions
+}
+
+#[derive(Debug)]
+pub enum ImportError {
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+    #[cfg(feature = "serde")]
+    Yaml(serde_yaml::Error),
+    InvalidTitle(String),
+    UnsupportedRepository(String),
+    EmptyRelease(Version),
+    InvalidChangelog(ChangelogCheckError),
+    Debian(DebianParseError),
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for ImportError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_yaml::Error> for ImportError {
+    fn from(value: serde_yaml::Error) -> Self {
+        Self::Yaml(value)
+    }
+}
+
+impl From<ChangelogCheckError> for ImportError {
+    fn from(value: ChangelogCheckError) -> Self {
+        Self::InvalidChangelog(value)
+    }
+}
+
+impl From<DebianParseError> for ImportError {
+    fn from(value: DebianParseError) -> Self {
+        Self::Debian(value)
+    }
+}
+
+impl Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "serde")]
+            ImportError::Json(err) => write!(f, "error while importing from json: {}", err),
+            #[cfg(feature = "serde")]
+            ImportError::Yaml(err) => write!(f, "error while importing from yaml: {}", err),
+            ImportError::InvalidTitle(title) => {
+                write!(f, "expected title to be \"Changelog\", got {:?}", title)
+            }
+            ImportError::UnsupportedRepository(url) => {
+                write!(f, "unsupported repository url {}", url)
+            }
+            ImportError::EmptyRelease(version) => {
+                write!(f, "release {} has no entries", version)
+            }
+            ImportError::InvalidChangelog(err) => {
+                write!(f, "the generated changelog is invalid: {}", err)
+            }
+            ImportError::Debian(err) => {
+                write!(f, "error while importing from debian changelog: {}", err)
+            }
+        }
+    }
+}
+
+impl Error for ImportError {}
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::*;
+
+    mod import {
+        use super::*;
+
+        #[test]
+        fn should_generate_changelog_from_yaml() {
+            let result = import(
+                r"
+intro: This is a mfking changelog y'all.
+repository: https://github.com/owner/repo
+unreleased:
+  fixed:
+    - The bull.
+  added:
+    - Some `new` bull.
+releases:
+  - version: 0.2.0
+    date: 2026-02-04
+    yanked: true
+    changes:
+      removed:
+        - The bull.
+        - The other bull.
+  - version: 0.1.0
+    date: 2026-01-01
+    changes:
+      added:
+        - Some bull.
+",
+                DocumentFormat::Yaml,
+            );
+            assert_eq!(
+                result.unwrap(),
+                r"# Changelog
+
+This is a mfking changelog y'all.
+
+## [Unreleased]
+
+### Added
+
+- Some `new` bull.
+
+### Fixed
+
+- The bull.
+
+## [0.2.0] - 2026-02-04 [YANKED]
+
+### Removed
+
+- The bull.
+- The other bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+"
+            );
+        }
+
+        #[test]
+        fn should_generate_changelog_from_json() {
+            let result = import(
+                r#"{
+  "repository": "https://github.com/owner/repo",
+  "releases": [
+    {
+      "version": "0.1.0",
+      "date": "2026-01-01",
+      "changes": { "added": ["Some bull."] }
+    }
+  ]
+}"#,
+                DocumentFormat::Json,
+            );
+            assert_eq!(
+                result.unwrap(),
+                format!(
+                    r"# Changelog
+
+{}
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+                    KEEP_A_CHANGELOG_INTRO
+                )
+            );
+        }
+
+        #[test]
+        fn should_generate_unreleased_only_changelog() {
+            let result = import(
+                r"
+repository: https://github.com/owner/repo
+unreleased:
+  added:
+    - Some bull.
+",
+                DocumentFormat::Yaml,
+            );
+            assert!(
+                result
+                    .unwrap()
+                    .ends_with("[Unreleased]: https://github.com/owner/repo/commits/HEAD\n")
+            );
+        }
+
+        #[test]
+        fn should_error_with_unknown_change_set_kind() {
+            let result = import(
+                r"
+repository: https://github.com/owner/repo
+unreleased:
+  fuckulated:
+    - Some bull.
+",
+                DocumentFormat::Yaml,
+            );
+            assert!(matches!(result, Err(ImportError::Yaml(_))), "{:?}", result);
+        }
+
+        #[test]
+        fn should_error_with_invalid_title() {
+            let result = import(
+                r"
+title: Changestream
+repository: https://github.com/owner/repo
+unreleased:
+  added:
+    - Some bull.
+",
+                DocumentFormat::Yaml,
+            );
+            assert!(
+                matches!(result, Err(ImportError::InvalidTitle(ref title)) if title == "Changestream"),
+                "{:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn should_error_with_unsupported_repository() {
+            let result = import(
+                r"
+repository: https://gitlab.com/owner/repo
+unreleased:
+  added:
+    - Some bull.
+",
+                DocumentFormat::Yaml,
+            );
+            assert!(
+                matches!(result, Err(ImportError::UnsupportedRepository(_))),
+                "{:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn should_error_with_empty_release() {
+            let result = import(
+                r"
+repository: https://github.com/owner/repo
+releases:
+  - version: 0.1.0
+    date: 2026-01-01
+    changes:
+      added: []
+",
+                DocumentFormat::Yaml,
+            );
+            assert!(
+                matches!(result, Err(ImportError::EmptyRelease(ref version)) if *version == Version::new(0, 1, 0)),
+                "{:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn should_error_with_unordered_releases() {
+            let result = import(
+                r"
+repository: https://github.com/owner/repo
+releases:
+  - version: 0.1.0
+    date: 2026-01-01
+    changes:
+      added:
+        - Some bull.
+  - version: 0.2.0
+    date: 2026-02-01
+    changes:
+      removed:
+        - The bull.
+",
+                DocumentFormat::Yaml,
+            );
+            assert!(
+                matches!(result, Err(ImportError::InvalidChangelog(_))),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    mod to_markdown {
+        use super::*;
+
+        #[test]
+        fn should_generate_empty_changelog() {
+            let result = ChangelogDocument::new("https://github.com/owner/repo".to_string())
+                .to_markdown()
+                .unwrap();
+            assert_eq!(
+                result,
+                format!(
+                    "# Changelog\n\n{}\n\n## [Unreleased]\n\n[Unreleased]: https://github.com/owner/repo/commits/HEAD\n",
+                    KEEP_A_CHANGELOG_INTRO
+                )
+            );
+        }
+    }
+}
@@ -1,17 +1,70 @@
+mod appstream;
+mod conventional;
+mod debian;
 mod debug;
+mod diff;
+mod encoding;
+#[cfg(feature = "serde")]
+mod export;
+#[cfg(feature = "serde")]
+mod format;
+mod fragments;
+mod git;
+mod import;
 mod lint;
+mod lsp;
+mod manifest;
+mod merge;
 mod parse;
+mod release;
+mod render;
+mod rpm;
+#[cfg(feature = "schema")]
+mod schema;
+mod verify_pr;
+mod workspace;
 
-use std::{error::Error, fmt::Display};
+use std::{error::Error, fmt::Display, ops::Range};
 
+pub use changelog_ast::{Encoding, LineColumn, LineIndex};
+pub use conventional::*;
+pub use debian::*;
 pub use debug::*;
+pub use diff::*;
+pub use encoding::*;
+#[cfg(feature = "serde")]
+pub use export::*;
+#[cfg(feature = "serde")]
+pub use format::*;
+pub use fragments::*;
+pub use git::*;
+pub use import::*;
+pub use lint::{
+    Baseline, BaselineError, ChangelogLintError, ManifestVersionMismatch, TagLintError, Violation,
+};
+pub use lsp::*;
+pub use manifest::*;
+pub use merge::*;
 pub use parse::*;
-
-use crate::lint::ChangelogLintError;
+pub use release::*;
+pub use render::*;
+pub use rpm::*;
+#[cfg(feature = "schema")]
+pub use schema::*;
+pub use verify_pr::*;
+pub use workspace::*;
 
 pub fn check(source: &str) -> Result<Changelog<'_>, ChangelogCheckError> {
+    check_package(source, None)
+}
+
+/// Checks the changelog of a package, whose releases may be tagged with its name as prefix.
+pub fn check_package<'source>(
+    source: &'source str,
+    package: Option<&str>,
+) -> Result<Changelog<'source>, ChangelogCheckError> {
     let changelog = Changelog::parse(source)?;
-    changelog.lint()?;
+    changelog.lint_package(package)?;
     Ok(changelog)
 }
 
@@ -33,6 +86,17 @@ impl From<ChangelogLintError> for ChangelogCheckError {
     }
 }
 
+impl ChangelogCheckError {
+    /// The range at fault in the checked source, if any.
+    pub fn range(&self, source: &str) -> Option<Range<usize>> {
+        match self {
+            ChangelogCheckError::ParseError(err) => err.range(),
+            // Lint errors are only found in changelogs that parse.
+            ChangelogCheckError::LintError(err) => err.range(&Changelog::parse(source).ok()?),
+        }
+    }
+}
+
 impl Display for ChangelogCheckError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -0,0 +1,368 @@
+use std::{collections::BTreeMap, error::Error, fmt::Display, path::Path};
+
+use toml::{Table, Value};
+
+use crate::{Changelog, lint::ChangelogLintError};
+
+/// A lint violation, identified by what it is about rather than by where it is in the file,
+/// so that it is still recognized once the lines around it change.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Violation {
+    rule: String,
+    /// The version of the release the violation is found in, or "Unreleased".
+    release: Option<String>,
+    fingerprint: String,
+}
+
+impl Violation {
+    pub fn new(changelog: &Changelog, error: &ChangelogLintError) -> Self {
+        let range = match error {
+            // These span all the reference definitions, their message is more specific.
+            ChangelogLintError::InvalidRerenceDefinition(_)
+            | ChangelogLintError::ForeignTagPackage(_) => None,
+            _ => error.range(changelog),
+        };
+        let (release, content) = match range {
+            Some(range) => {
+                let source = changelog.source();
+                let line = source[range.start..].lines().next().unwrap_or_default();
+                (release_at(changelog, range.start), line.trim().to_string())
+            }
+            None => (None, error.to_string()),
+        };
+        Self {
+            rule: error.rule().to_string(),
+            release,
+            fingerprint: fingerprint(&content),
+        }
+    }
+
+    pub fn rule(&self) -> &str {
+        &self.rule
+    }
+
+    pub fn release(&self) -> Option<&str> {
+        self.release.as_deref()
+    }
+
+    pub fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+}
+
+// The section the offset falls in. Reference definitions belong to the release they label.
+fn release_at(changelog: &Changelog, offset: usize) -> Option<String> {
+    if let Some(reference_definition) = changelog
+        .reference_definitions()
+        .iter()
+        .find(|reference_definition| reference_definition.range().contains(&offset))
+    {
+        return Some(reference_definition.label().to_string());
+    }
+    if let Some(release) = changelog
+        .releases()
+        .iter()
+        .rev()
+        .find(|release| release.heading().start <= offset)
+    {
+        return Some(release.version().to_string());
+    }
+    changelog
+        .unreleased()
+        .as_ref()
+        .filter(|unreleased| unreleased.heading().start <= offset)
+        .map(|_| "Unreleased".to_string())
+}
+
+// A 64 bits FNV-1a hash, which unlike the hasher of the standard library is stable across
+// releases of Rust.
+fn fingerprint(content: &str) -> String {
+    let hash = content.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// The lint violations of changelogs at some point in time, so that only new ones fail the
+/// check.
+///
+/// Violations are recorded by file, as passed on the command line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    violations: BTreeMap<String, Vec<Violation>>,
+}
+
+impl Baseline {
+    pub fn parse(content: &str) -> Result<Self, BaselineError> {
+        let table: Table = content.parse().map_err(BaselineError::InvalidContent)?;
+        let mut baseline = Self::default();
+        let entries = match table.get("violations") {
+            Some(Value::Array(entries)) => entries.as_slice(),
+            Some(_) => return Err(BaselineError::InvalidEntry(0)),
+            None => &[],
+        };
+        for (index, entry) in entries.iter().enumerate() {
+            let field = |key: &str| entry.get(key).and_then(Value::as_str);
+            let (Some(file), Some(rule), Some(fingerprint)) =
+                (field("file"), field("rule"), field("fingerprint"))
+            else {
+                return Err(BaselineError::InvalidEntry(index));
+            };
+            baseline
+                .violations
+                .entry(file.to_string())
+                .or_default()
+                .push(Violation {
+                    rule: rule.to_string(),
+                    release: field("release").map(str::to_string),
+                    fingerprint: fingerprint.to_string(),
+                });
+        }
+        Ok(baseline)
+    }
+
+    pub fn read(path: &Path) -> Result<Self, BaselineError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    /// The violations recorded for the file.
+    pub fn violations(&self, file: &str) -> &[Violation] {
+        self.violations
+            .get(file)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Records the violations of a changelog, replacing those previously recorded for its file.
+    pub fn record(&mut self, file: &str, changelog: &Changelog, errors: &[ChangelogLintError]) {
+        let mut violations: Vec<Violation> = errors
+            .iter()
+            .map(|error| Violation::new(changelog, error))
+            .collect();
+        violations.sort();
+        self.violations.insert(file.to_string(), violations);
+    }
+
+    /// The violations of a changelog that aren't recorded for its file. Every recorded
+    /// violation only excuses one, so repeating it is still reported.
+    pub fn unrecorded(
+        &self,
+        file: &str,
+        changelog: &Changelog,
+        errors: Vec<ChangelogLintError>,
+    ) -> Vec<ChangelogLintError> {
+        let mut recorded = self.violations(file).to_vec();
+        errors
+            .into_iter()
+            .filter(|error| {
+                let violation = Violation::new(changelog, error);
+                match recorded.iter().position(|entry| *entry == violation) {
+                    Some(index) => {
+                        recorded.swap_remove(index);
+                        false
+                    }
+                    None => true,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let string = |value: &str| Value::String(value.to_string());
+        let mut first = true;
+        for (file, violations) in &self.violations {
+            for violation in violations {
+                if !first {
+                    writeln!(f)?;
+                }
+                first = false;
+                writeln!(f, "[[violations]]")?;
+                writeln!(f, "file = {}", string(file))?;
+                writeln!(f, "rule = {}", string(&violation.rule))?;
+                if let Some(release) = &violation.release {
+                    writeln!(f, "release = {}", string(release))?;
+                }
+                writeln!(f, "fingerprint = {}", string(&violation.fingerprint))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(std::io::Error),
+    InvalidContent(toml::de::Error),
+    /// The index of the violation missing a field.
+    InvalidEntry(usize),
+}
+
+impl From<std::io::Error> for BaselineError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineError::Io(err) => write!(f, "unable to read baseline: {}", err),
+            BaselineError::InvalidContent(err) => write!(f, "unable to parse baseline: {}", err),
+            BaselineError::InvalidEntry(index) => write!(
+                f,
+                "expected baseline violation {} to have a file, a rule and a fingerprint",
+                index
+            ),
+        }
+    }
+}
+
+impl Error for BaselineError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Some intro.
+
+## [0.4.0] - 2026-04-01
+
+### Removed
+
+- The bull.
+
+### Added
+
+- Some bull.
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- More bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.4.0]: https://github.com/owner/repo/compare/v0.2.0...v0.4.0
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    mod violation {
+        use super::*;
+
+        #[test]
+        fn should_identify_rule_and_release() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let violations: Vec<Violation> = changelog
+                .lint_violations(None)
+                .iter()
+                .map(|error| Violation::new(&changelog, error))
+                .collect();
+            let identities: Vec<(&str, Option<&str>)> = violations
+                .iter()
+                .map(|violation| (violation.rule(), violation.release()))
+                .collect();
+            assert_eq!(
+                identities,
+                vec![
+                    ("version-gap", Some("0.2.0")),
+                    ("change-set-order", Some("0.4.0")),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_not_depend_on_offsets() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let shifted = SOURCE.replace("Some intro.", "Some much longer intro.\n\nIndeed.");
+            let shifted_changelog = Changelog::parse(&shifted).unwrap();
+            let violations = |changelog: &Changelog| -> Vec<Violation> {
+                changelog
+                    .lint_violations(None)
+                    .iter()
+                    .map(|error| Violation::new(changelog, error))
+                    .collect()
+            };
+            assert_eq!(violations(&changelog), violations(&shifted_changelog));
+        }
+    }
+
+    mod unrecorded {
+        use super::*;
+
+        #[test]
+        fn should_only_report_new_violations() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let mut baseline = Baseline::default();
+            baseline.record("CHANGELOG.md", &changelog, &changelog.lint_violations(None));
+            assert_eq!(
+                baseline.unrecorded("CHANGELOG.md", &changelog, changelog.lint_violations(None)),
+                vec![]
+            );
+
+            let edited = SOURCE.replace(
+                "### Added\n\n- More bull.",
+                "### Removed\n\n- More bull.\n\n### Added\n\n- Even more bull.",
+            );
+            let edited = Changelog::parse(&edited).unwrap();
+            let unrecorded =
+                baseline.unrecorded("CHANGELOG.md", &edited, edited.lint_violations(None));
+            assert_eq!(unrecorded.len(), 1);
+            assert_eq!(unrecorded[0].rule(), "change-set-order");
+            assert_eq!(
+                Violation::new(&edited, &unrecorded[0]).release(),
+                Some("0.2.0")
+            );
+        }
+
+        #[test]
+        fn should_report_all_violations_of_other_files() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let mut baseline = Baseline::default();
+            baseline.record("CHANGELOG.md", &changelog, &changelog.lint_violations(None));
+            assert_eq!(
+                baseline
+                    .unrecorded(
+                        "other/CHANGELOG.md",
+                        &changelog,
+                        changelog.lint_violations(None)
+                    )
+                    .len(),
+                2
+            );
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn should_round_trip() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let mut baseline = Baseline::default();
+            baseline.record("CHANGELOG.md", &changelog, &changelog.lint_violations(None));
+            assert_eq!(Baseline::parse(&baseline.to_string()).unwrap(), baseline);
+        }
+
+        #[test]
+        fn should_error_for_incomplete_entry() {
+            assert!(matches!(
+                Baseline::parse("[[violations]]\nfile = \"CHANGELOG.md\"\n"),
+                Err(BaselineError::InvalidEntry(0))
+            ));
+        }
+    }
+}
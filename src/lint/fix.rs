@@ -0,0 +1,405 @@
+use std::ops::Range;
+
+use changelog_ast::CowStr;
+use itertools::Itertools;
+
+use crate::{
+    Changelog, LineEnding, ReferenceDefinition, Release, TextEdit, Unreleased, UnreleasedHeading,
+    Yanked,
+    import::to_changes,
+    lint::{
+        ChangelogLintError, ordered_change_set::OrderedChangeSet, ref_def_linters::RefDefLinter,
+    },
+    merge::{entries, unreleased_entries},
+};
+
+impl ChangelogLintError {
+    /// Whether [`Changelog::autofix`] gets rid of the problem.
+    pub fn is_autofixable(&self) -> bool {
+        matches!(
+            self,
+            ChangelogLintError::UnorderedChangeSets(_, _)
+                | ChangelogLintError::UnorderedReferenceDefinitions(_, _)
+                | ChangelogLintError::DanglingReferenceDefinition(_)
+                | ChangelogLintError::InvalidRerenceDefinition(_)
+        )
+    }
+}
+
+impl<'source> Changelog<'source> {
+    /// Renders the changelog with the problems of the autofixable lints fixed.
+    ///
+    /// The change sets of every section are put back in order, and the reference definitions are
+    /// regenerated from the sections, following the repository and tag scheme of the oldest
+    /// one. The output is canonical markdown.
+    pub fn autofix(&self) -> String {
+        let unreleased = self.unreleased().as_ref().map(|unreleased| {
+            Unreleased::new(
+                UnreleasedHeading::new(unreleased.heading().clone()),
+                to_changes(unreleased_entries(self)),
+            )
+        });
+        let releases = self
+            .releases()
+            .iter()
+            .map(|release| {
+                Release::new(
+                    release.heading().clone(),
+                    release.version().clone(),
+                    *release.date(),
+                    to_changes(entries(release.changes())),
+                    Yanked(release.is_yanked()),
+                )
+            })
+            .collect();
+        let reference_definitions = self
+            .fixed_reference_definitions(unreleased.is_some())
+            .unwrap_or_else(|| self.reference_definitions().to_vec());
+        Changelog::new(
+            "",
+            self.title().clone(),
+            unreleased,
+            releases,
+            reference_definitions,
+        )
+        .to_markdown()
+    }
+
+    /// The edits fixing the problems of the autofixable lints in place.
+    ///
+    /// Unlike [`Changelog::autofix`], the rest of the source is left as is: only the change
+    /// sets of the sections they are unordered in, and the reference definitions when they
+    /// change, are replaced. The replacements use the line endings of the source.
+    pub fn autofix_edits(&self) -> Vec<TextEdit> {
+        let source = self.source();
+        let line_ending = LineEnding::detect(source);
+        let mut edits = vec![];
+        let unreleased = self
+            .unreleased()
+            .iter()
+            .map(|unreleased| unreleased.changes());
+        let releases = self.releases().iter().map(|release| release.changes());
+        for changes in unreleased.chain(releases) {
+            let mut change_sets: Vec<OrderedChangeSet> =
+                changes.iter().map(OrderedChangeSet).collect();
+            if change_sets.is_sorted() {
+                continue;
+            }
+            let Some(range) = block(
+                source,
+                change_sets.iter().map(|change_set| change_set.0.range()),
+            ) else {
+                continue;
+            };
+            change_sets.sort();
+            let text = change_sets
+                .iter()
+                .map(|change_set| trim_line_endings(&source[change_set.0.range()]))
+                .join("\n\n");
+            edits.push(TextEdit::new(range, line_ending.apply(&text)));
+        }
+
+        let reference_definitions = self
+            .reference_definitions()
+            .iter()
+            .map(|reference_definition| reference_definition.range().clone());
+        if let Some(range) = block(source, reference_definitions)
+            && let Some(fixed) = self.fixed_reference_definitions(self.unreleased().is_some())
+        {
+            let text = fixed
+                .iter()
+                .map(|reference_definition| {
+                    format!(
+                        "[{}]: {}",
+                        reference_definition.label(),
+                        reference_definition.dest()
+                    )
+                })
+                .join("\n");
+            let text = line_ending.apply(&text);
+            if source[range.clone()] != text {
+                edits.push(TextEdit::new(range, text));
+            }
+        }
+        edits
+    }
+
+    // None when the repository can't be told from the reference definitions.
+    pub(crate) fn fixed_reference_definitions(
+        &self,
+        has_unreleased: bool,
+    ) -> Option<Vec<ReferenceDefinition<'source>>> {
+        let oldest = self
+            .releases()
+            .last()
+            .map(|release| release.version().to_string());
+        let linter = self
+            .reference_definitions()
+            .iter()
+            .find(|reference_definition| Some(reference_definition.label()) == oldest.as_deref())
+            .or(self.reference_definitions().last())
+            .and_then(RefDefLinter::try_new)?;
+
+        let labels = self
+            .releases()
+            .iter()
+            .rev()
+            .map(|release| release.version().to_string())
+            .chain(has_unreleased.then(|| "Unreleased".to_string()));
+        let mut reference_definitions = vec![];
+        let mut previous: Option<String> = None;
+        for label in labels {
+            let dest = match &previous {
+                Some(previous) => linter.diff_destination(previous, &label),
+                None => linter.release_destination(&label),
+            };
+            reference_definitions.push(ReferenceDefinition::new(
+                label.clone(),
+                CowStr::from(dest),
+                0..0,
+            ));
+            previous = Some(label);
+        }
+        reference_definitions.reverse();
+        Some(reference_definitions)
+    }
+}
+
+// The range spanning consecutive blocks, without the line endings that follow the last one.
+fn block(source: &str, ranges: impl Iterator<Item = Range<usize>>) -> Option<Range<usize>> {
+    let (start, end) = ranges.fold(None, |block: Option<(usize, usize)>, range| match block {
+        Some((start, end)) => Some((start.min(range.start), end.max(range.end))),
+        None => Some((range.start, range.end)),
+    })?;
+    Some(start..start + trim_line_endings(&source[start..end]).len())
+}
+
+fn trim_line_endings(text: &str) -> &str {
+    text.trim_end_matches(['\r', '\n'])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod autofix {
+        use super::*;
+
+        #[test]
+        fn should_fix_autofixable_problems() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Fixed
+
+- The bull.
+
+### Added
+
+- New bull.
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- More bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.3.0
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+            )
+            .unwrap();
+            let error = changelog.lint().unwrap_err();
+            assert!(error.is_autofixable(), "{:?}", error);
+
+            let fixed = changelog.autofix();
+            assert_eq!(
+                fixed,
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- New bull.
+
+### Fixed
+
+- The bull.
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- More bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+"
+            );
+            assert_eq!(Changelog::parse(&fixed).unwrap().lint(), Ok(()));
+        }
+
+        #[test]
+        fn should_fix_unordered_release_change_sets() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [0.1.0] - 2026-01-01
+
+### Removed
+
+- The bull.
+
+### Added
+
+- Some bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+            )
+            .unwrap();
+            let error = changelog.lint().unwrap_err();
+            assert!(error.is_autofixable(), "{:?}", error);
+
+            let fixed = changelog.autofix();
+            assert_eq!(
+                fixed,
+                r"# Changelog
+
+Some intro.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+### Removed
+
+- The bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+"
+            );
+            assert_eq!(Changelog::parse(&fixed).unwrap().lint(), Ok(()));
+        }
+
+        #[test]
+        fn should_not_fix_version_problems() {
+            let error = ChangelogLintError::GapBetweenVersions(
+                semver::Version::new(0, 3, 0),
+                semver::Version::new(0, 1, 0),
+            );
+            assert!(!error.is_autofixable());
+        }
+    }
+
+    mod autofix_edits {
+        use super::*;
+
+        #[test]
+        fn should_only_touch_offending_blocks() {
+            let source = r"# Changelog
+
+Some *intro*.
+
+## [Unreleased]
+
+### Fixed
+
+* The bull.
+
+### Added
+
+* New bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+* Some bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+"
+            .replace('\n', "\r\n");
+            let changelog = Changelog::parse(&source).unwrap();
+            let fixed = changelog
+                .autofix_edits()
+                .iter()
+                .rev()
+                .fold(source.clone(), |fixed, edit| edit.apply(&fixed));
+            assert_eq!(
+                fixed,
+                r"# Changelog
+
+Some *intro*.
+
+## [Unreleased]
+
+### Added
+
+* New bull.
+
+### Fixed
+
+* The bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+* Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+"
+                .replace('\n', "\r\n")
+            );
+            assert_eq!(Changelog::parse(&fixed).unwrap().lint(), Ok(()));
+        }
+
+        #[test]
+        fn should_be_empty_for_valid_changelog() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+",
+            )
+            .unwrap();
+            assert_eq!(changelog.autofix_edits(), vec![]);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::{error::Error, fmt::Display};
+
+use semver::Version;
+
+use crate::{ChangeSetKind, Changelog};
+
+impl<'source> Changelog<'source> {
+    /// Computes the version the unreleased changes call for, if any.
+    ///
+    /// Changed and removed entries are breaking, and bump the major version. Added and
+    /// deprecated entries bump the minor version, and the others the patch version. Before
+    /// 1.0.0, breaking changes only bump the minor version and everything else the patch
+    /// version. A pre-release graduates to its release instead.
+    pub fn next_version(&self) -> Option<Version> {
+        let latest = self.releases().first()?.version();
+        let kinds: Vec<ChangeSetKind> = self
+            .unreleased()
+            .as_ref()?
+            .changes()
+            .iter()
+            .filter(|change_set| !change_set.items().is_empty())
+            .map(|change_set| change_set.kind())
+            .collect();
+        if kinds.is_empty() {
+            return None;
+        }
+        if !latest.pre.is_empty() {
+            return Some(Version::new(latest.major, latest.minor, latest.patch));
+        }
+
+        let breaking = kinds
+            .iter()
+            .any(|kind| matches!(kind, ChangeSetKind::Changed | ChangeSetKind::Removed));
+        let feature = kinds
+            .iter()
+            .any(|kind| matches!(kind, ChangeSetKind::Added | ChangeSetKind::Deprecated));
+        let next = if latest.major == 0 {
+            if breaking {
+                Version::new(0, latest.minor + 1, 0)
+            } else {
+                Version::new(0, latest.minor, latest.patch + 1)
+            }
+        } else if breaking {
+            Version::new(latest.major + 1, 0, 0)
+        } else if feature {
+            Version::new(latest.major, latest.minor + 1, 0)
+        } else {
+            Version::new(latest.major, latest.minor, latest.patch + 1)
+        };
+        Some(next)
+    }
+
+    /// Verifies the version of a package manifest agrees with the changelog.
+    ///
+    /// The manifest must be at the latest release or, when there are unreleased changes, at
+    /// the [next version](Changelog::next_version). Without any release, anything goes.
+    pub fn lint_manifest_version(&self, version: &Version) -> Result<(), ManifestVersionMismatch> {
+        let Some(latest) = self.releases().first() else {
+            return Ok(());
+        };
+        let expected: Vec<Version> = std::iter::once(latest.version().clone())
+            .chain(self.next_version())
+            .collect();
+        if expected.contains(version) {
+            Ok(())
+        } else {
+            Err(ManifestVersionMismatch {
+                version: version.clone(),
+                expected,
+            })
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestVersionMismatch {
+    pub version: Version,
+    pub expected: Vec<Version>,
+}
+
+impl Display for ManifestVersionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let expected: Vec<String> = self.expected.iter().map(Version::to_string).collect();
+        write!(
+            f,
+            "expected manifest version {} to be {}",
+            self.version,
+            expected.join(" or ")
+        )
+    }
+}
+
+impl Error for ManifestVersionMismatch {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn changelog_source(latest: &str, unreleased: &str) -> String {
+        let unreleased = if unreleased.is_empty() {
+            String::new()
+        } else {
+            format!("## [Unreleased]\n\n{}\n", unreleased)
+        };
+        let unreleased_ref_def = if unreleased.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "[Unreleased]: https://github.com/owner/repo/compare/v{}...HEAD\n",
+                latest
+            )
+        };
+        format!(
+            "# Changelog\n\nSome intro.\n\n{}## [{}] - 2026-01-01\n\n### Added\n\n- Some bull.\n\n{}[{}]: https://github.com/owner/repo/releases/tag/v{}\n",
+            unreleased, latest, unreleased_ref_def, latest, latest
+        )
+    }
+
+    mod next_version {
+        use super::*;
+
+        #[test]
+        fn should_be_none_without_unreleased_changes() {
+            let source = changelog_source("1.2.3", "");
+            let changelog = Changelog::parse(&source).unwrap();
+            assert_eq!(changelog.next_version(), None);
+        }
+
+        #[test]
+        fn should_bump_major_for_breaking_changes() {
+            let source = changelog_source(
+                "1.2.3",
+                "### Fixed\n\n- The bull.\n\n### Removed\n\n- The other bull.\n",
+            );
+            let changelog = Changelog::parse(&source).unwrap();
+            assert_eq!(changelog.next_version(), Some(Version::new(2, 0, 0)));
+        }
+
+        #[test]
+        fn should_bump_minor_for_features() {
+            let source = changelog_source("1.2.3", "### Added\n\n- New bull.\n");
+            let changelog = Changelog::parse(&source).unwrap();
+            assert_eq!(changelog.next_version(), Some(Version::new(1, 3, 0)));
+        }
+
+        #[test]
+        fn should_bump_patch_for_fixes() {
+            let source = changelog_source("1.2.3", "### Security\n\n- The bull.\n");
+            let changelog = Changelog::parse(&source).unwrap();
+            assert_eq!(changelog.next_version(), Some(Version::new(1, 2, 4)));
+        }
+
+        #[test]
+        fn should_shift_bumps_before_1_0_0() {
+            let source = changelog_source("0.2.3", "### Changed\n\n- The bull.\n");
+            let changelog = Changelog::parse(&source).unwrap();
+            assert_eq!(changelog.next_version(), Some(Version::new(0, 3, 0)));
+
+            let source = changelog_source("0.2.3", "### Added\n\n- New bull.\n");
+            let changelog = Changelog::parse(&source).unwrap();
+            assert_eq!(changelog.next_version(), Some(Version::new(0, 2, 4)));
+        }
+    }
+
+    mod lint_manifest_version {
+        use super::*;
+
+        #[test]
+        fn should_accept_latest_release_or_next_version() {
+            let source = changelog_source("1.2.3", "### Added\n\n- New bull.\n");
+            let changelog = Changelog::parse(&source).unwrap();
+            assert_eq!(
+                changelog.lint_manifest_version(&Version::new(1, 2, 3)),
+                Ok(())
+            );
+            assert_eq!(
+                changelog.lint_manifest_version(&Version::new(1, 3, 0)),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn should_error_with_other_version() {
+            let source = changelog_source("1.2.3", "");
+            let changelog = Changelog::parse(&source).unwrap();
+            assert_eq!(
+                changelog.lint_manifest_version(&Version::new(1, 2, 4)),
+                Err(ManifestVersionMismatch {
+                    version: Version::new(1, 2, 4),
+                    expected: vec![Version::new(1, 2, 3)],
+                })
+            );
+        }
+    }
+}
@@ -5,7 +5,7 @@ use itertools::Itertools;
 use semver::Version;
 
 use crate::{
-    Changelog,
+    Changelog, TagScheme,
     lint::{
         ordered_change_set::OrderedChangeSet,
         ref_def_linters::{RefDefLintError, RefDefLinter},
@@ -13,106 +13,147 @@ use crate::{
     },
 };
 
+mod baseline;
+mod fix;
+mod manifest;
 mod ordered_change_set;
-mod ref_def_linters;
+pub(crate) mod ref_def_linters;
+mod tags;
 mod version_gap;
 
+pub use baseline::*;
+pub use manifest::*;
+pub use tags::*;
+
 impl<'source> Changelog<'source> {
     pub fn lint(&self) -> Result<(), ChangelogLintError> {
-        self.release_versions_in_descending_order()?;
-        self.no_gap_between_versions()?;
-        self.release_dates_in_descending_order()?;
-        self.release_change_sets_in_lexicographical_order()?;
-        self.reference_definitions_in_descending_order()?;
-        self.no_dangling_reference_definitions()?;
-        self.valid_reference_definition_destinations()?;
-        Ok(())
+        self.lint_package(None)
+    }
+
+    /// Lints the changelog of a package, whose releases may be tagged with its name as prefix,
+    /// like in a monorepo.
+    pub fn lint_package(&self, package: Option<&str>) -> Result<(), ChangelogLintError> {
+        first(self.lint_violations(package))
+    }
+
+    /// All the violations of the lints, in the order [Changelog::lint_package] reports the
+    /// first one.
+    pub fn lint_violations(&self, package: Option<&str>) -> Vec<ChangelogLintError> {
+        let mut violations = vec![];
+        self.release_versions_in_descending_order(&mut violations);
+        self.no_gap_between_versions(&mut violations);
+        self.release_dates_in_descending_order(&mut violations);
+        self.release_change_sets_in_lexicographical_order(&mut violations);
+        self.reference_definitions_in_descending_order(&mut violations);
+        self.dangling_reference_definitions(&mut violations);
+        self.reference_definition_destinations(package, &mut violations);
+        violations
     }
 
-    fn release_versions_in_descending_order(&self) -> Result<(), ChangelogLintError> {
+    /// The tag scheme the reference definitions follow, the plain one by default.
+    pub fn tag_scheme(&self) -> TagScheme {
+        self.reference_definitions()
+            .last()
+            .and_then(RefDefLinter::try_new)
+            .map(|linter| linter.scheme().clone())
+            .unwrap_or_default()
+    }
+
+    fn release_versions_in_descending_order(&self, violations: &mut Vec<ChangelogLintError>) {
         let releases = self.releases();
         for (previous, current) in releases.iter().map(|r| r.version()).tuple_windows() {
             // Releases are unique so they can't be the same neither. TODO: different error type?
             if previous <= current {
-                return Err(ChangelogLintError::UnorderedReleaseVersions(
+                violations.push(ChangelogLintError::UnorderedReleaseVersions(
                     previous.clone(),
                     current.clone(),
                 ));
             }
         }
-        Ok(())
     }
 
-    fn no_gap_between_versions(&self) -> Result<(), ChangelogLintError> {
+    fn no_gap_between_versions(&self, violations: &mut Vec<ChangelogLintError>) {
         let releases = self.releases();
         for (previous, current) in releases.iter().map(|r| r.version()).tuple_windows() {
             if !versions_differ_by_one(current, previous) {
-                return Err(ChangelogLintError::GapBetweenVersions(
+                violations.push(ChangelogLintError::GapBetweenVersions(
                     previous.clone(),
                     current.clone(),
                 ));
             }
         }
-        Ok(())
     }
 
-    fn release_dates_in_descending_order(&self) -> Result<(), ChangelogLintError> {
+    fn release_dates_in_descending_order(&self, violations: &mut Vec<ChangelogLintError>) {
         let releases = self.releases();
         for (previous, current) in releases.iter().map(|r| r.date()).tuple_windows() {
             // The date could be the same, since it's a granularity of one day.
             if previous < current {
-                return Err(ChangelogLintError::UnorderedReleaseDates(
+                violations.push(ChangelogLintError::UnorderedReleaseDates(
                     *previous, *current,
                 ));
             }
         }
-        Ok(())
     }
 
-    fn release_change_sets_in_lexicographical_order(&self) -> Result<(), ChangelogLintError> {
-        if let Some(unreleased) = self.unreleased() {
-            let changes = unreleased.changes();
-            for (previous, current) in changes.iter().map(OrderedChangeSet).tuple_windows() {
-                if previous >= current {
-                    return Err(ChangelogLintError::UnorderedChangeSets(
-                        previous.0.range(),
-                        current.0.range(),
-                    ));
-                }
-            }
-        }
-
-        let releases = self.releases();
-        for release in releases {
-            let changes = release.changes();
+    fn release_change_sets_in_lexicographical_order(
+        &self,
+        violations: &mut Vec<ChangelogLintError>,
+    ) {
+        let unreleased = self
+            .unreleased()
+            .iter()
+            .map(|unreleased| unreleased.changes());
+        let releases = self.releases().iter().map(|release| release.changes());
+        for changes in unreleased.chain(releases) {
             for (previous, current) in changes.iter().map(OrderedChangeSet).tuple_windows() {
                 if previous >= current {
-                    return Err(ChangelogLintError::UnorderedChangeSets(
+                    violations.push(ChangelogLintError::UnorderedChangeSets(
                         previous.0.range(),
                         current.0.range(),
                     ));
                 }
             }
         }
-        Ok(())
     }
 
-    fn reference_definitions_in_descending_order(&self) -> Result<(), ChangelogLintError> {
+    fn reference_definitions_in_descending_order(&self, violations: &mut Vec<ChangelogLintError>) {
         let reference_definitions = self.reference_definitions();
         for (previous, current) in reference_definitions.iter().tuple_windows() {
-            let previous_version = Version::parse(previous.label()).unwrap();
-            let current_version = Version::parse(current.label()).unwrap();
+            // The unreleased definition, if any, sits on top of all the versioned ones.
+            if current.is_unreleased() {
+                violations.push(ChangelogLintError::UnorderedReferenceDefinitions(
+                    previous.range().clone(),
+                    current.range().clone(),
+                ));
+                continue;
+            }
+            if previous.is_unreleased() {
+                continue;
+            }
+            // Labels that aren't versions are reported as dangling.
+            let (Ok(previous_version), Ok(current_version)) = (
+                Version::parse(previous.label()),
+                Version::parse(current.label()),
+            ) else {
+                continue;
+            };
             if previous_version <= current_version {
-                return Err(ChangelogLintError::UnorderedReferenceDefinitions(
+                violations.push(ChangelogLintError::UnorderedReferenceDefinitions(
                     previous.range().clone(),
                     current.range().clone(),
                 ));
             }
         }
-        Ok(())
     }
 
     pub fn no_dangling_reference_definitions(&self) -> Result<(), ChangelogLintError> {
+        let mut violations = vec![];
+        self.dangling_reference_definitions(&mut violations);
+        first(violations)
+    }
+
+    fn dangling_reference_definitions(&self, violations: &mut Vec<ChangelogLintError>) {
         // This lint assumes the parsing eliminates all releases with broken links. So all the releases
         // have working reference definitions, but the opposite is not necessarily true.
         let release_versions: HashSet<_> = self
@@ -122,34 +163,74 @@ impl<'source> Changelog<'source> {
             .collect();
 
         for def in self.reference_definitions() {
-            if !release_versions.contains(&Version::parse(def.label()).unwrap()) {
-                return Err(ChangelogLintError::DanglingReferenceDefinition(
+            let dangling = match def.is_unreleased() {
+                true => self.unreleased().is_none(),
+                false => Version::parse(def.label())
+                    .map_or(true, |version| !release_versions.contains(&version)),
+            };
+            if dangling {
+                violations.push(ChangelogLintError::DanglingReferenceDefinition(
                     def.range().clone(),
                 ));
             }
         }
-        Ok(())
     }
 
     /// We're going over all the reference definitions, enforcing their destination URLs are both consistent
-    /// and valid for their given version control provider.
-    pub fn valid_reference_definition_destinations(&self) -> Result<(), ChangelogLintError> {
+    /// and valid for their given version control provider. Tags may only be prefixed with the name
+    /// of the package, if any.
+    pub fn valid_reference_definition_destinations(
+        &self,
+        package: Option<&str>,
+    ) -> Result<(), ChangelogLintError> {
+        let mut violations = vec![];
+        self.reference_definition_destinations(package, &mut violations);
+        first(violations)
+    }
+
+    fn reference_definition_destinations(
+        &self,
+        package: Option<&str>,
+        violations: &mut Vec<ChangelogLintError>,
+    ) {
         // The changelog parsing requires at least one of unreleased or one released version,
         // guaranteeing, that there is going to be at least one ref def.
-        let first = self.reference_definitions().iter().next_back().unwrap();
-        let linter = RefDefLinter::try_new(first).ok_or(
-            ChangelogLintError::UnknownReferenceDefinitionFormat(first.range().clone()),
-        )?;
+        let Some(first) = self.reference_definitions().iter().next_back() else {
+            return;
+        };
+        let Some(linter) = RefDefLinter::try_new(first) else {
+            violations.push(ChangelogLintError::UnknownReferenceDefinitionFormat(
+                first.range().clone(),
+            ));
+            return;
+        };
+        if let Some(prefix) = linter.scheme().package()
+            && Some(prefix) != package
+        {
+            // Every destination would be reported otherwise.
+            violations.push(ChangelogLintError::ForeignTagPackage(prefix.to_string()));
+            return;
+        }
         // We only expect the first release, the one at the bottom, to be categorized as a "release"
         // definition. All other entries should be diffs definition with the previous version.
-        linter.lint_release_definition(first)?;
+        if let Err(err) = linter.lint_release_definition(first) {
+            violations.push(err.into());
+        }
 
         // Now we restart the iteration and we go in pairs.
         for (previous, current) in self.reference_definitions().iter().rev().tuple_windows() {
-            linter.lint_diff_definition(previous, current)?;
+            if let Err(err) = linter.lint_diff_definition(previous, current) {
+                violations.push(err.into());
+            }
         }
+    }
+}
 
-        Ok(())
+// The first violation, as an error.
+fn first(violations: Vec<ChangelogLintError>) -> Result<(), ChangelogLintError> {
+    match violations.into_iter().next() {
+        Some(violation) => Err(violation),
+        None => Ok(()),
     }
 }
 
@@ -164,6 +245,7 @@ pub enum ChangelogLintError {
     DanglingReferenceDefinition(Range<usize>),
     UnknownReferenceDefinitionFormat(Range<usize>),
     InvalidRerenceDefinition(RefDefLintError),
+    ForeignTagPackage(String),
 }
 
 impl From<RefDefLintError> for ChangelogLintError {
@@ -172,6 +254,52 @@ impl From<RefDefLintError> for ChangelogLintError {
     }
 }
 
+impl ChangelogLintError {
+    /// The identifier of the lint rule violated.
+    pub fn rule(&self) -> &'static str {
+        match self {
+            ChangelogLintError::UnorderedReleaseVersions(_, _) => "release-version-order",
+            ChangelogLintError::GapBetweenVersions(_, _) => "version-gap",
+            ChangelogLintError::UnorderedReleaseDates(_, _) => "release-date-order",
+            ChangelogLintError::UnorderedChangeSets(_, _) => "change-set-order",
+            ChangelogLintError::UnorderedReferenceDefinitions(_, _) => "reference-definition-order",
+            ChangelogLintError::DanglingReferenceDefinition(_) => "dangling-reference-definition",
+            ChangelogLintError::UnknownReferenceDefinitionFormat(_) => {
+                "reference-definition-format"
+            }
+            ChangelogLintError::InvalidRerenceDefinition(_) => "reference-definition-destination",
+            ChangelogLintError::ForeignTagPackage(_) => "tag-package",
+        }
+    }
+
+    /// The source range at fault in the changelog, if any.
+    pub fn range(&self, changelog: &Changelog) -> Option<Range<usize>> {
+        match self {
+            ChangelogLintError::UnorderedReleaseVersions(_, version)
+            | ChangelogLintError::GapBetweenVersions(_, version) => changelog
+                .releases()
+                .iter()
+                .find(|release| release.version() == version)
+                .map(|release| release.heading().clone()),
+            ChangelogLintError::UnorderedReleaseDates(_, date) => changelog
+                .releases()
+                .iter()
+                .find(|release| release.date() == date)
+                .map(|release| release.heading().clone()),
+            ChangelogLintError::UnorderedChangeSets(_, range)
+            | ChangelogLintError::UnorderedReferenceDefinitions(_, range)
+            | ChangelogLintError::DanglingReferenceDefinition(range)
+            | ChangelogLintError::UnknownReferenceDefinitionFormat(range) => Some(range.clone()),
+            ChangelogLintError::InvalidRerenceDefinition(_)
+            | ChangelogLintError::ForeignTagPackage(_) => {
+                let first = changelog.reference_definitions().first()?;
+                let last = changelog.reference_definitions().last()?;
+                Some(first.range().start..last.range().end)
+            }
+        }
+    }
+}
+
 impl Display for ChangelogLintError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -209,6 +337,11 @@ impl Display for ChangelogLintError {
                 write!(f, "unknown reference definition format found {:?}", range)
             }
             ChangelogLintError::InvalidRerenceDefinition(err) => write!(f, "{}", err),
+            ChangelogLintError::ForeignTagPackage(package) => write!(
+                f,
+                "found release tags prefixed with {}, which isn't the name of the package",
+                package
+            ),
         }
     }
 }
@@ -439,6 +572,30 @@ This is a mfking changelog y'all.
             );
         }
 
+        #[test]
+        fn should_error_with_reference_definition_not_labeled_with_version() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+This is a mfking changelog y'all.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+[foo]: https://example.com",
+            )
+            .unwrap();
+            let result = changelog.lint();
+            assert_eq!(
+                result,
+                Err(ChangelogLintError::DanglingReferenceDefinition(157..183))
+            );
+        }
+
         #[test]
         fn should_error_with_invalid_reference_definition_destination() {
             let changelog = Changelog::parse(
@@ -496,5 +653,135 @@ This is a mfking changelog y'all.
             let result = changelog.lint();
             assert_eq!(result, Ok(()));
         }
+
+        #[test]
+        fn should_work_with_valid_changelog_with_unreleased() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+This is a mfking changelog y'all.
+
+## [Unreleased]
+
+### Added
+
+- More bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0",
+            )
+            .unwrap();
+            let result = changelog.lint();
+            assert_eq!(result, Ok(()));
+        }
+
+        #[test]
+        fn should_error_with_unreleased_reference_definition_not_on_top() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+This is a mfking changelog y'all.
+
+## [Unreleased]
+
+### Added
+
+- More bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD",
+            )
+            .unwrap();
+            let result = changelog.lint();
+            assert!(
+                matches!(
+                    result,
+                    Err(ChangelogLintError::UnorderedReferenceDefinitions(_, _))
+                ),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    mod lint_package {
+        use super::*;
+
+        const MONOREPO: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- More bull.
+
+## [1.2.3] - 2026-02-01
+
+### Added
+
+- Some bull.
+
+## [1.2.2] - 2026-01-01
+
+### Added
+
+- Less bull.
+
+[Unreleased]: https://github.com/owner/monorepo/compare/bull@1.2.3...HEAD
+[1.2.3]: https://github.com/owner/monorepo/compare/bull@1.2.2...bull@1.2.3
+[1.2.2]: https://github.com/owner/monorepo/releases/tag/bull@1.2.2
+";
+
+        #[test]
+        fn should_work_with_tags_prefixed_with_package() {
+            let changelog = Changelog::parse(MONOREPO).unwrap();
+            assert_eq!(changelog.lint_package(Some("bull")), Ok(()));
+            assert_eq!(
+                changelog.tag_scheme(),
+                TagScheme::PackageAt("bull".to_string())
+            );
+        }
+
+        #[test]
+        fn should_error_with_tags_of_other_package() {
+            let changelog = Changelog::parse(MONOREPO).unwrap();
+            assert_eq!(
+                changelog.lint_package(Some("cow")),
+                Err(ChangelogLintError::ForeignTagPackage("bull".to_string()))
+            );
+            assert_eq!(
+                changelog.lint(),
+                Err(ChangelogLintError::ForeignTagPackage("bull".to_string()))
+            );
+        }
+
+        #[test]
+        fn should_error_with_inconsistent_tags() {
+            let source = MONOREPO.replace(
+                "compare/bull@1.2.2...bull@1.2.3",
+                "compare/bull-v1.2.2...bull-v1.2.3",
+            );
+            let changelog = Changelog::parse(&source).unwrap();
+            let result = changelog.lint_package(Some("bull"));
+            assert!(
+                matches!(result, Err(ChangelogLintError::InvalidRerenceDefinition(_))),
+                "{:?}",
+                result
+            );
+        }
     }
 }
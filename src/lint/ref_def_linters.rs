@@ -2,25 +2,72 @@ use std::{error::Error, fmt::Display};
 
 use url::{Host, Url};
 
-use crate::ReferenceDefinition;
+use crate::{ReferenceDefinition, TagScheme};
 
 pub enum RefDefLinter {
     GitHub(GitHubRefDefLinter),
 }
 
 impl RefDefLinter {
+    /// Constructs a linter from a reference definition, following the tag scheme it uses.
     pub fn try_new(ref_def: &ReferenceDefinition) -> Option<Self> {
         // TODO: this should be a specific error.
         let Ok(url) = Url::parse(ref_def.dest()) else {
             return None;
         };
+        let linter = Self::try_from_url(&url)?;
+        let scheme = linter
+            .tag(&url)
+            .and_then(|tag| TagScheme::detect(tag, ref_def.label()))
+            .unwrap_or_default();
+        Some(linter.with_scheme(scheme))
+    }
 
-        if let Some(linter) = GitHubRefDefLinter::try_new(&url) {
+    /// Constructs a linter from any URL pointing into the repository, like its home page.
+    ///
+    /// The releases are expected to follow the plain tag scheme.
+    pub fn try_from_url(url: &Url) -> Option<Self> {
+        if let Some(linter) = GitHubRefDefLinter::try_new(url) {
             return Some(Self::GitHub(linter));
         }
         None
     }
 
+    pub fn with_scheme(self, scheme: TagScheme) -> Self {
+        match self {
+            RefDefLinter::GitHub(linter) => {
+                RefDefLinter::GitHub(GitHubRefDefLinter { scheme, ..linter })
+            }
+        }
+    }
+
+    pub fn scheme(&self) -> &TagScheme {
+        match self {
+            RefDefLinter::GitHub(linter) => &linter.scheme,
+        }
+    }
+
+    // The tag a destination points to, if any.
+    fn tag<'url>(&self, url: &'url Url) -> Option<&'url str> {
+        match self {
+            RefDefLinter::GitHub(linter) => linter.tag(url),
+        }
+    }
+
+    /// The expected destination of the first, and oldest, reference definition.
+    pub fn release_destination(&self, label: &str) -> String {
+        match self {
+            RefDefLinter::GitHub(linter) => linter.release_destination(label),
+        }
+    }
+
+    /// The expected destination of a reference definition following the one labeled `previous`.
+    pub fn diff_destination(&self, previous: &str, current: &str) -> String {
+        match self {
+            RefDefLinter::GitHub(linter) => linter.diff_destination(previous, current),
+        }
+    }
+
     pub fn lint_release_definition(
         &self,
         ref_def: &ReferenceDefinition,
@@ -69,6 +116,7 @@ impl Error for RefDefLintError {}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GitHubRefDefLinter {
     base_url: String,
+    scheme: TagScheme,
 }
 
 impl GitHubRefDefLinter {
@@ -88,23 +136,53 @@ impl GitHubRefDefLinter {
                 base_url.set_path(new_path.as_str());
                 Some(Self {
                     base_url: base_url.into(),
+                    scheme: TagScheme::default(),
                 })
             }
             _ => None,
         }
     }
 
+    fn release_destination(&self, label: &str) -> String {
+        if label.eq_ignore_ascii_case("unreleased") {
+            format!("{}/commits/HEAD", self.base_url)
+        } else {
+            format!("{}/releases/tag/{}", self.base_url, self.scheme.tag(label))
+        }
+    }
+
+    fn tag<'url>(&self, url: &'url Url) -> Option<&'url str> {
+        let path = url.as_str().strip_prefix(self.base_url.as_str())?;
+        if let Some(tag) = path.strip_prefix("/releases/tag/") {
+            return Some(tag);
+        }
+        let (_, current) = path.strip_prefix("/compare/")?.split_once("...")?;
+        Some(current)
+    }
+
+    fn diff_destination(&self, previous: &str, current: &str) -> String {
+        if current.eq_ignore_ascii_case("unreleased") {
+            format!(
+                "{}/compare/{}...HEAD",
+                self.base_url,
+                self.scheme.tag(previous)
+            )
+        } else {
+            format!(
+                "{}/compare/{}...{}",
+                self.base_url,
+                self.scheme.tag(previous),
+                self.scheme.tag(current)
+            )
+        }
+    }
+
     fn lint_release_definition(
         &self,
         ref_def: &ReferenceDefinition<'_>,
     ) -> Result<(), RefDefLintError> {
-        let label = ref_def.label();
         let url = Url::parse(ref_def.dest())?;
-        let expected = if label.eq_ignore_ascii_case("unreleased") {
-            format!("{}/commits/HEAD", self.base_url)
-        } else {
-            format!("{}/releases/tag/v{}", self.base_url, label)
-        };
+        let expected = self.release_destination(ref_def.label());
         if url.as_str() != expected {
             return Err(RefDefLintError::InvalidUrl(format!(
                 "{} not matching expected {}",
@@ -121,16 +199,7 @@ impl GitHubRefDefLinter {
         current: &ReferenceDefinition<'_>,
     ) -> Result<(), RefDefLintError> {
         let url = Url::parse(current.dest())?;
-        let expected = if current.label().eq_ignore_ascii_case("unreleased") {
-            format!("{}/compare/v{}...HEAD", self.base_url, previous.label(),)
-        } else {
-            format!(
-                "{}/compare/v{}...v{}",
-                self.base_url,
-                previous.label(),
-                current.label()
-            )
-        };
+        let expected = self.diff_destination(previous.label(), current.label());
         if url.as_str() != expected {
             return Err(RefDefLintError::InvalidUrl(format!(
                 "{} not matching expected {}",
@@ -379,6 +448,42 @@ mod test {
         }
     }
 
+    mod ref_def_linter {
+        use std::ops::Range;
+
+        use super::*;
+
+        #[test]
+        fn should_follow_scheme_of_ref_def() {
+            let init_ref_def = ReferenceDefinition::new(
+                "1.2.2".into(),
+                "https://github.com/owner/monorepo/releases/tag/bull@1.2.2".into(),
+                Range::default(),
+            );
+            let linter = RefDefLinter::try_new(&init_ref_def).unwrap();
+            assert_eq!(linter.scheme(), &TagScheme::PackageAt("bull".to_string()));
+            assert_eq!(linter.lint_release_definition(&init_ref_def), Ok(()));
+            let ref_def = ReferenceDefinition::new(
+                "1.2.3".into(),
+                "https://github.com/owner/monorepo/compare/bull@1.2.2...bull@1.2.3".into(),
+                Range::default(),
+            );
+            assert_eq!(linter.lint_diff_definition(&init_ref_def, &ref_def), Ok(()));
+        }
+
+        #[test]
+        fn should_default_to_plain_scheme() {
+            let init_ref_def = ReferenceDefinition::new(
+                "1.2.2".into(),
+                "https://github.com/owner/repo/releases/tag/release-1.2.2".into(),
+                Range::default(),
+            );
+            let linter = RefDefLinter::try_new(&init_ref_def).unwrap();
+            assert_eq!(linter.scheme(), &TagScheme::Plain);
+            assert!(linter.lint_release_definition(&init_ref_def).is_err());
+        }
+    }
+
     mod to_base_url {
         use super::*;
 
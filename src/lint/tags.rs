@@ -0,0 +1,169 @@
+use std::{error::Error, fmt::Display};
+
+use chrono::NaiveDate;
+use semver::Version;
+
+use crate::{Changelog, GitTag};
+
+impl<'source> Changelog<'source> {
+    /// Cross-checks the releases against the tags of the repository.
+    ///
+    /// Every release that isn't yanked must be tagged, and released no earlier than its
+    /// tagged commit. Every tag following the [tag scheme](Changelog::tag_scheme) of the
+    /// changelog must be documented. Other tags, like those of the other packages of a
+    /// monorepo, are ignored.
+    pub fn lint_tags(&self, tags: &[GitTag]) -> Result<(), TagLintError> {
+        let scheme = self.tag_scheme();
+        for release in self.releases() {
+            let name = scheme.release_tag(release.version());
+            match tags.iter().find(|tag| tag.name() == name) {
+                Some(tag) if release.date() < tag.date() => {
+                    return Err(TagLintError::ReleasedBeforeTag(
+                        release.version().clone(),
+                        *release.date(),
+                        *tag.date(),
+                    ));
+                }
+                Some(_) => {}
+                None if release.is_yanked() => {}
+                None => return Err(TagLintError::MissingTag(release.version().clone(), name)),
+            }
+        }
+
+        for tag in tags {
+            let Some(version) = scheme.version(tag.name()) else {
+                continue;
+            };
+            if !self
+                .releases()
+                .iter()
+                .any(|release| release.version() == &version)
+            {
+                return Err(TagLintError::UndocumentedTag(tag.name().to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagLintError {
+    MissingTag(Version, String),
+    UndocumentedTag(String),
+    ReleasedBeforeTag(Version, NaiveDate, NaiveDate),
+}
+
+impl Display for TagLintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TagLintError::MissingTag(version, tag) => {
+                write!(f, "expected release {} to be tagged {}", version, tag)
+            }
+            TagLintError::UndocumentedTag(tag) => {
+                write!(f, "found tag {} without a matching release", tag)
+            }
+            TagLintError::ReleasedBeforeTag(version, release_date, tag_date) => write!(
+                f,
+                "expected release {} dated {} not to predate its tagged commit dated {}",
+                version, release_date, tag_date
+            ),
+        }
+    }
+}
+
+impl Error for TagLintError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Some intro.
+
+## [0.3.0] - 2026-03-01 [YANKED]
+
+### Added
+
+- Broken bull.
+
+## [0.2.0] - 2026-02-04
+
+### Added
+
+- More bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.3.0]: https://github.com/owner/repo/compare/v0.2.0...v0.3.0
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    fn tag(name: &str, date: &str) -> GitTag {
+        GitTag::new(
+            name.to_string(),
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+        )
+    }
+
+    mod lint_tags {
+        use super::*;
+
+        #[test]
+        fn should_work_with_tagged_releases() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let tags = [
+                tag("v0.1.0", "2026-01-01"),
+                tag("v0.2.0", "2026-02-03"),
+                tag("some-other-tag", "2026-02-03"),
+            ];
+            assert_eq!(changelog.lint_tags(&tags), Ok(()));
+        }
+
+        #[test]
+        fn should_error_with_missing_tag() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let tags = [tag("v0.2.0", "2026-02-03")];
+            assert_eq!(
+                changelog.lint_tags(&tags),
+                Err(TagLintError::MissingTag(
+                    Version::new(0, 1, 0),
+                    "v0.1.0".to_string()
+                ))
+            );
+        }
+
+        #[test]
+        fn should_error_with_undocumented_tag() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let tags = [
+                tag("v0.1.0", "2026-01-01"),
+                tag("v0.1.1", "2026-01-02"),
+                tag("v0.2.0", "2026-02-03"),
+            ];
+            assert_eq!(
+                changelog.lint_tags(&tags),
+                Err(TagLintError::UndocumentedTag("v0.1.1".to_string()))
+            );
+        }
+
+        #[test]
+        fn should_error_with_release_dated_before_tag() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let tags = [tag("v0.1.0", "2026-01-01"), tag("v0.2.0", "2026-02-05")];
+            assert_eq!(
+                changelog.lint_tags(&tags),
+                Err(TagLintError::ReleasedBeforeTag(
+                    Version::new(0, 2, 0),
+                    NaiveDate::from_ymd_opt(2026, 2, 4).unwrap(),
+                    NaiveDate::from_ymd_opt(2026, 2, 5).unwrap()
+                ))
+            );
+        }
+    }
+}
@@ -0,0 +1,748 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    ops::Range,
+    panic::{AssertUnwindSafe, catch_unwind},
+};
+
+use changelog_ast::{Encoding, LineColumn, LineIndex};
+use lsp_server::{Connection, ErrorCode, ExtractError, Message, Notification, Request, Response};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, CompletionItem, CompletionItemKind, CompletionOptions,
+    CompletionResponse, Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DocumentSymbol, DocumentSymbolResponse,
+    Hover, HoverContents, HoverProviderCapability, MarkupContent, MarkupKind, OneOf, Position,
+    PublishDiagnosticsParams, ServerCapabilities, SymbolKind, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextEdit, Uri, WorkspaceEdit,
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as LspNotification, PublishDiagnostics,
+    },
+    request::{CodeActionRequest, Completion, DocumentSymbolRequest, HoverRequest},
+};
+
+use crate::{ChangeSetKind, Changelog, ChangelogLintError, Changes, Manifest};
+
+/// Runs a language server for changelog files over stdio, until the client shuts it down.
+pub fn run_language_server() -> Result<(), LanguageServerError> {
+    let (connection, io_threads) = Connection::stdio();
+    serve(connection)?;
+    io_threads.join()?;
+    Ok(())
+}
+
+/// Serves the language server protocol over the given connection, until the client shuts
+/// it down.
+pub fn serve(connection: Connection) -> Result<(), LanguageServerError> {
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![" ".to_string()]),
+            ..Default::default()
+        }),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    };
+    // Serializing the capabilities can't fail, they only hold strings, numbers and booleans.
+    connection.initialize(serde_json::to_value(capabilities).unwrap())?;
+
+    let mut server = LanguageServer::default();
+    for message in &connection.receiver {
+        let reply = match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                Some(Message::Response(server.handle_request(request)))
+            }
+            Message::Notification(notification) => server
+                .handle_notification(notification)
+                .map(Message::Notification),
+            Message::Response(_) => None,
+        };
+        if let Some(reply) = reply {
+            connection
+                .sender
+                .send(reply)
+                .map_err(|_| LanguageServerError::Disconnected)?;
+        }
+    }
+    Ok(())
+}
+
+// The open documents, as last synchronized by the client.
+#[derive(Default)]
+struct LanguageServer {
+    documents: HashMap<Uri, String>,
+}
+
+impl LanguageServer {
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            <CodeActionRequest as lsp_types::request::Request>::METHOD => {
+                respond::<CodeActionRequest>(request, |params| {
+                    let uri = params.text_document.uri.clone();
+                    let source = self.documents.get(&uri)?;
+                    Some(code_actions(source, &uri, params))
+                })
+            }
+            <Completion as lsp_types::request::Request>::METHOD => {
+                respond::<Completion>(request, |params| {
+                    let position = params.text_document_position;
+                    let source = self.documents.get(&position.text_document.uri)?;
+                    Some(CompletionResponse::Array(completions(
+                        source,
+                        position.position,
+                    )))
+                })
+            }
+            <HoverRequest as lsp_types::request::Request>::METHOD => {
+                respond::<HoverRequest>(request, |params| {
+                    let position = params.text_document_position_params;
+                    let source = self.documents.get(&position.text_document.uri)?;
+                    hover(source, offset(&LineIndex::new(source), position.position))
+                })
+            }
+            <DocumentSymbolRequest as lsp_types::request::Request>::METHOD => {
+                respond::<DocumentSymbolRequest>(request, |params| {
+                    let source = self.documents.get(&params.text_document.uri)?;
+                    Some(DocumentSymbolResponse::Nested(document_symbols(source)))
+                })
+            }
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request {}", request.method),
+            ),
+        }
+    }
+
+    // Returns the diagnostics to publish, if the notification changed any.
+    fn handle_notification(&mut self, notification: Notification) -> Option<Notification> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    notification.extract(DidOpenTextDocument::METHOD).ok()?;
+                let document = params.text_document;
+                self.documents.insert(document.uri.clone(), document.text);
+                Some(self.publish_diagnostics(document.uri, Some(document.version)))
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    notification.extract(DidChangeTextDocument::METHOD).ok()?;
+                // With full synchronization, the last change holds the whole document.
+                let text = params.content_changes.into_iter().last()?.text;
+                let document = params.text_document;
+                self.documents.insert(document.uri.clone(), text);
+                Some(self.publish_diagnostics(document.uri, Some(document.version)))
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    notification.extract(DidCloseTextDocument::METHOD).ok()?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                // Clears the diagnostics of the closed document.
+                Some(Notification::new(
+                    PublishDiagnostics::METHOD.to_string(),
+                    PublishDiagnosticsParams::new(uri, vec![], None),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: Uri, version: Option<i32>) -> Notification {
+        let diagnostics = match self.documents.get(&uri) {
+            Some(source) => {
+                let package = package(&uri);
+                unpanicked(|| diagnostics(source, package.as_deref())).unwrap_or_else(|message| {
+                    vec![Diagnostic {
+                        severity: Some(DiagnosticSeverity::ERROR),
+                        source: Some("rs-changelog".to_string()),
+                        message: format!("unable to check the changelog: {}", message),
+                        ..Default::default()
+                    }]
+                })
+            }
+            None => vec![],
+        };
+        Notification::new(
+            PublishDiagnostics::METHOD.to_string(),
+            PublishDiagnosticsParams::new(uri, diagnostics, version),
+        )
+    }
+}
+
+fn respond<R: lsp_types::request::Request>(
+    request: Request,
+    handle: impl FnOnce(R::Params) -> R::Result,
+) -> Response {
+    let id = request.id.clone();
+    match request.extract::<R::Params>(R::METHOD) {
+        Ok((id, params)) => match unpanicked(|| handle(params)) {
+            Ok(result) => Response::new_ok(id, result),
+            Err(message) => Response::new_err(id, ErrorCode::InternalError as i32, message),
+        },
+        Err(ExtractError::JsonError { error, .. }) => {
+            Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string())
+        }
+        Err(ExtractError::MethodMismatch(request)) => Response::new_err(
+            id,
+            ErrorCode::MethodNotFound as i32,
+            format!("unsupported request {}", request.method),
+        ),
+    }
+}
+
+// Runs the computation, returning the message it panicked with instead of taking the server
+// down with it. Nothing outlives a panicking computation, so none can be seen broken.
+fn unpanicked<T>(compute: impl FnOnce() -> T) -> Result<T, String> {
+    catch_unwind(AssertUnwindSafe(compute)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unexpected panic".to_string())
+    })
+}
+
+// The package of the changelog, for its monorepo tags, from the manifest next to it.
+fn package(uri: &Uri) -> Option<String> {
+    let path = url::Url::parse(uri.as_str()).ok()?.to_file_path().ok()?;
+    Manifest::adjacent(&path)?.package_name().ok()?
+}
+
+fn diagnostics(source: &str, package: Option<&str>) -> Vec<Diagnostic> {
+    let index = LineIndex::new(source);
+    let diagnostic = |range: Range<usize>, message: String| Diagnostic {
+        range: lsp_range(&index, range),
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("rs-changelog".to_string()),
+        message,
+        ..Default::default()
+    };
+    let changelog = match Changelog::parse(source) {
+        Ok(changelog) => changelog,
+        Err(err) => {
+            let range = err.range().unwrap_or(0..0);
+            return vec![diagnostic(range, err.to_string())];
+        }
+    };
+    changelog
+        .lint_violations(package)
+        .into_iter()
+        .map(|err| diagnostic(err.range(&changelog).unwrap_or(0..0), err.to_string()))
+        .collect()
+}
+
+fn code_actions(source: &str, uri: &Uri, params: CodeActionParams) -> Vec<CodeActionOrCommand> {
+    let Ok(changelog) = Changelog::parse(source) else {
+        return vec![];
+    };
+    let fixable = changelog
+        .lint_violations(package(uri).as_deref())
+        .iter()
+        .any(ChangelogLintError::is_autofixable);
+    if !fixable {
+        return vec![];
+    }
+    let index = LineIndex::new(source);
+    let edits: Vec<TextEdit> = changelog
+        .autofix_edits()
+        .into_iter()
+        .map(|edit| {
+            TextEdit::new(
+                lsp_range(&index, edit.range().clone()),
+                edit.text().to_string(),
+            )
+        })
+        .collect();
+    if edits.is_empty() {
+        return vec![];
+    }
+    vec![CodeActionOrCommand::CodeAction(CodeAction {
+        title: "Fix the change set order and reference definitions".to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(params.context.diagnostics),
+        edit: Some(WorkspaceEdit::new(HashMap::from([(uri.clone(), edits)]))),
+        is_preferred: Some(true),
+        ..Default::default()
+    })]
+}
+
+// Change set kinds, right after the `### ` of a heading.
+fn completions(source: &str, position: Position) -> Vec<CompletionItem> {
+    let index = LineIndex::new(source);
+    let start = offset(&index, Position::new(position.line, 0));
+    let end = offset(&index, position);
+    let Some(typed) = source[start..end].strip_prefix("### ") else {
+        return vec![];
+    };
+    if !typed.chars().all(|c| c.is_ascii_alphabetic()) {
+        return vec![];
+    }
+    [
+        ChangeSetKind::Added,
+        ChangeSetKind::Changed,
+        ChangeSetKind::Deprecated,
+        ChangeSetKind::Fixed,
+        ChangeSetKind::Removed,
+        ChangeSetKind::Security,
+    ]
+    .into_iter()
+    .map(|kind| kind.to_string())
+    .filter(|kind| kind.to_lowercase().starts_with(&typed.to_lowercase()))
+    .map(|kind| CompletionItem {
+        label: kind,
+        kind: Some(CompletionItemKind::ENUM_MEMBER),
+        ..Default::default()
+    })
+    .collect()
+}
+
+// The date and compare link of the section whose heading is under the cursor.
+fn hover(source: &str, offset: usize) -> Option<Hover> {
+    let changelog = Changelog::parse(source).ok()?;
+    let index = LineIndex::new(source);
+    let on = |heading: &Range<usize>| heading.start <= offset && offset <= heading.end;
+    let link = |label: &str| {
+        changelog
+            .reference_definitions()
+            .iter()
+            .find(|reference_definition| reference_definition.label() == label)
+            .map(|reference_definition| format!("\n\n[Compare]({})", reference_definition.dest()))
+            .unwrap_or_default()
+    };
+
+    let (heading, value) = if let Some(unreleased) = changelog
+        .unreleased()
+        .as_ref()
+        .filter(|unreleased| on(unreleased.heading()))
+    {
+        let label = changelog
+            .reference_definitions()
+            .iter()
+            .find(|reference_definition| reference_definition.is_unreleased())
+            .map(|reference_definition| reference_definition.label())
+            .unwrap_or("Unreleased");
+        (
+            unreleased.heading(),
+            format!("**Unreleased** changes{}", link(label)),
+        )
+    } else {
+        let release = changelog
+            .releases()
+            .iter()
+            .find(|release| on(release.heading()))?;
+        let yanked = if release.is_yanked() { ", yanked" } else { "" };
+        (
+            release.heading(),
+            format!(
+                "**{}**, released on {}{}{}",
+                release.version(),
+                release.date(),
+                yanked,
+                link(&release.version().to_string())
+            ),
+        )
+    };
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(lsp_range(&index, heading.clone())),
+    })
+}
+
+// The sections of the changelog, with their change sets.
+#[allow(deprecated)]
+fn document_symbols(source: &str) -> Vec<DocumentSymbol> {
+    let Ok(changelog) = Changelog::parse(source) else {
+        return vec![];
+    };
+    let index = LineIndex::new(source);
+    let section =
+        |name: String, detail: Option<String>, heading: &Range<usize>, changes: &Changes| {
+            let children: Vec<DocumentSymbol> = changes
+                .iter()
+                .map(|change_set| DocumentSymbol {
+                    name: change_set.kind().to_string(),
+                    detail: Some(format!("{} entries", change_set.items().len())),
+                    kind: SymbolKind::ENUM,
+                    tags: None,
+                    deprecated: None,
+                    range: lsp_range(&index, change_set.range()),
+                    selection_range: lsp_range(&index, change_set.range()),
+                    children: None,
+                })
+                .collect();
+            let end = changes
+                .iter()
+                .map(|change_set| change_set.range().end)
+                .max()
+                .unwrap_or(heading.end);
+            DocumentSymbol {
+                name,
+                detail,
+                kind: SymbolKind::NAMESPACE,
+                tags: None,
+                deprecated: None,
+                range: lsp_range(&index, heading.start..end),
+                selection_range: lsp_range(&index, heading.clone()),
+                children: Some(children),
+            }
+        };
+
+    let mut symbols = vec![];
+    if let Some(unreleased) = changelog.unreleased() {
+        symbols.push(section(
+            "Unreleased".to_string(),
+            None,
+            unreleased.heading(),
+            unreleased.changes(),
+        ));
+    }
+    for release in changelog.releases() {
+        symbols.push(section(
+            release.version().to_string(),
+            Some(release.date().to_string()),
+            release.heading(),
+            release.changes(),
+        ));
+    }
+    symbols
+}
+
+fn lsp_range(index: &LineIndex, range: Range<usize>) -> lsp_types::Range {
+    lsp_types::Range::new(position(index, range.start), position(index, range.end))
+}
+
+// LSP positions are 0-based, and count the characters of a line in UTF-16 code units.
+fn position(index: &LineIndex, offset: usize) -> Position {
+    let position = index.line_column(offset, Encoding::Utf16);
+    Position::new(position.line as u32 - 1, position.column as u32 - 1)
+}
+
+fn offset(index: &LineIndex, position: Position) -> usize {
+    let position = LineColumn::new(position.line as usize + 1, position.character as usize + 1);
+    index.offset(position, Encoding::Utf16)
+}
+
+#[derive(Debug)]
+pub enum LanguageServerError {
+    Io(std::io::Error),
+    Protocol(lsp_server::ProtocolError),
+    Disconnected,
+}
+
+impl From<std::io::Error> for LanguageServerError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<lsp_server::ProtocolError> for LanguageServerError {
+    fn from(value: lsp_server::ProtocolError) -> Self {
+        Self::Protocol(value)
+    }
+}
+
+impl Display for LanguageServerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LanguageServerError::Io(err) => write!(f, "language server i/o failed: {}", err),
+            LanguageServerError::Protocol(err) => {
+                write!(f, "language server protocol error: {}", err)
+            }
+            LanguageServerError::Disconnected => {
+                write!(f, "language client disconnected unexpectedly")
+            }
+        }
+    }
+}
+
+impl Error for LanguageServerError {}
+
+#[cfg(test)]
+mod test {
+    use lsp_types::{
+        CodeActionContext, PartialResultParams, TextDocumentIdentifier, WorkDoneProgressParams,
+    };
+
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- New bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    mod diagnostics {
+        use super::*;
+
+        #[test]
+        fn should_be_empty_for_valid_changelog() {
+            assert_eq!(diagnostics(SOURCE, None), vec![]);
+        }
+
+        #[test]
+        fn should_point_at_offending_release() {
+            let source = SOURCE.replace("## [0.1.0] - 2026-01-01", "## [0.1.0] - 2026-01-01\n\n### Added\n\n- Bull.\n\n## [0.0.1] - 2026-01-02").replace(
+                "[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0",
+                "[0.1.0]: https://github.com/owner/repo/compare/v0.0.1...v0.1.0\n[0.0.1]: https://github.com/owner/repo/releases/tag/v0.0.1",
+            );
+            let diagnostics = diagnostics(&source, None);
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(diagnostics[0].range.start, Position::new(16, 0));
+        }
+
+        #[test]
+        fn should_publish_all_violations() {
+            let source = SOURCE
+                .replace(
+                    "### Added\n\n- New bull.",
+                    "### Fixed\n\n- The bull.\n\n### Added\n\n- New bull.",
+                )
+                .replace("releases/tag/v0.1.0", "releases/tag/v0.0.9");
+            let diagnostics = diagnostics(&source, None);
+            let lines: Vec<u32> = diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.range.start.line)
+                .collect();
+            assert_eq!(lines, vec![10, 20], "{:?}", diagnostics);
+        }
+
+        #[test]
+        fn should_point_at_reference_definition_not_labeled_with_version() {
+            let source = format!("{}[foo]: https://example.com\n", SOURCE);
+            let diagnostics = diagnostics(&source, None);
+            assert!(
+                diagnostics
+                    .iter()
+                    .any(|diagnostic| diagnostic.range.start == Position::new(18, 0)),
+                "{:?}",
+                diagnostics
+            );
+        }
+
+        #[test]
+        fn should_point_at_empty_title() {
+            let diagnostics = diagnostics("# ", None);
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(diagnostics[0].range.start, Position::new(0, 0));
+        }
+    }
+
+    mod unpanicked {
+        use super::*;
+
+        #[test]
+        fn should_return_panic_message() {
+            assert_eq!(unpanicked(|| 42), Ok(42));
+            assert_eq!(
+                unpanicked(|| -> () { panic!("boom {}", 42) }),
+                Err("boom 42".to_string())
+            );
+        }
+    }
+
+    mod code_actions {
+        use super::*;
+
+        fn edits(source: &str) -> Vec<TextEdit> {
+            let uri: Uri = "file:///nowhere/CHANGELOG.md".parse().unwrap();
+            let params = CodeActionParams {
+                text_document: TextDocumentIdentifier::new(uri.clone()),
+                range: lsp_range(&LineIndex::new(source), 0..0),
+                context: CodeActionContext::default(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            };
+            let actions = code_actions(source, &uri, params);
+            let [CodeActionOrCommand::CodeAction(action)] = actions.as_slice() else {
+                panic!("{:?}", actions);
+            };
+            action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri].clone()
+        }
+
+        #[test]
+        fn should_offer_autofix() {
+            let source = SOURCE.replace("compare/v0.1.0...HEAD", "compare/v0.0.9...HEAD");
+            let range = source.find("[Unreleased]:").unwrap()..source.len() - 1;
+            assert_eq!(
+                edits(&source),
+                vec![TextEdit::new(
+                    lsp_range(&LineIndex::new(&source), range.clone()),
+                    SOURCE[range].to_string()
+                )]
+            );
+        }
+
+        #[test]
+        fn should_keep_line_endings() {
+            let source = SOURCE
+                .replace("compare/v0.1.0...HEAD", "compare/v0.0.9...HEAD")
+                .replace('\n', "\r\n");
+            let range = source.find("[Unreleased]:").unwrap()..source.len() - 2;
+            assert_eq!(
+                edits(&source),
+                vec![TextEdit::new(
+                    lsp_range(&LineIndex::new(&source), range.clone()),
+                    SOURCE.replace('\n', "\r\n")[range].to_string()
+                )]
+            );
+        }
+
+        #[test]
+        fn should_not_offer_for_valid_changelog() {
+            let uri: Uri = "file:///nowhere/CHANGELOG.md".parse().unwrap();
+            let params = CodeActionParams {
+                text_document: TextDocumentIdentifier::new(uri.clone()),
+                range: lsp_range(&LineIndex::new(SOURCE), 0..0),
+                context: CodeActionContext::default(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            };
+            assert_eq!(code_actions(SOURCE, &uri, params), vec![]);
+        }
+    }
+
+    mod completions {
+        use super::*;
+
+        #[test]
+        fn should_complete_change_set_kinds() {
+            let source = "# Changelog\n\n### De";
+            let labels: Vec<String> = completions(source, Position::new(2, 6))
+                .into_iter()
+                .map(|item| item.label)
+                .collect();
+            assert_eq!(labels, vec!["Deprecated"]);
+            assert_eq!(completions(source, Position::new(2, 4)).len(), 6);
+            assert_eq!(completions(source, Position::new(0, 2)), vec![]);
+        }
+    }
+
+    mod hover {
+        use super::*;
+
+        #[test]
+        fn should_show_date_and_compare_link() {
+            let offset = SOURCE.find("## [0.1.0]").unwrap() + 5;
+            let Some(Hover {
+                contents: HoverContents::Markup(content),
+                ..
+            }) = hover(SOURCE, offset)
+            else {
+                panic!("no hover");
+            };
+            assert_eq!(
+                content.value,
+                "**0.1.0**, released on 2026-01-01\n\n[Compare](https://github.com/owner/repo/releases/tag/v0.1.0)"
+            );
+            assert_eq!(hover(SOURCE, SOURCE.find("- Some").unwrap()), None);
+        }
+    }
+
+    mod document_symbols {
+        use super::*;
+
+        #[test]
+        fn should_list_sections_and_change_sets() {
+            let symbols = document_symbols(SOURCE);
+            let names: Vec<&str> = symbols.iter().map(|symbol| symbol.name.as_str()).collect();
+            assert_eq!(names, vec!["Unreleased", "0.1.0"]);
+            let children = symbols[1].children.as_ref().unwrap();
+            assert_eq!(children[0].name, "Added");
+            assert_eq!(symbols[1].selection_range.start, Position::new(10, 0));
+        }
+    }
+
+    mod position {
+        use super::*;
+
+        #[test]
+        fn should_count_utf16_code_units() {
+            let source = "a\n😀b\nc";
+            let index = LineIndex::new(source);
+            let b = source.find('b').unwrap();
+            assert_eq!(position(&index, b), Position::new(1, 2));
+            assert_eq!(offset(&index, Position::new(1, 2)), b);
+            assert_eq!(offset(&index, Position::new(5, 0)), source.len());
+        }
+    }
+
+    mod serve {
+        use super::*;
+
+        #[test]
+        fn should_publish_diagnostics_of_opened_documents() {
+            let (server, client) = Connection::memory();
+            let handle = std::thread::spawn(move || serve(server));
+            let send = |message: Message| client.sender.send(message).unwrap();
+
+            send(Message::Request(Request::new(
+                1.into(),
+                "initialize".to_string(),
+                serde_json::json!({"capabilities": {}}),
+            )));
+            assert!(matches!(
+                client.receiver.recv().unwrap(),
+                Message::Response(_)
+            ));
+            send(Message::Notification(Notification::new(
+                "initialized".to_string(),
+                serde_json::json!({}),
+            )));
+            send(Message::Notification(Notification::new(
+                DidOpenTextDocument::METHOD.to_string(),
+                serde_json::json!({"textDocument": {
+                    "uri": "file:///nowhere/CHANGELOG.md",
+                    "languageId": "markdown",
+                    "version": 1,
+                    "text": "Not a changelog.",
+                }}),
+            )));
+            let Message::Notification(notification) = client.receiver.recv().unwrap() else {
+                panic!("expected diagnostics");
+            };
+            let params: PublishDiagnosticsParams =
+                serde_json::from_value(notification.params).unwrap();
+            assert_eq!(params.diagnostics.len(), 1);
+
+            send(Message::Request(Request::new(
+                2.into(),
+                "shutdown".to_string(),
+                serde_json::Value::Null,
+            )));
+            assert!(matches!(
+                client.receiver.recv().unwrap(),
+                Message::Response(_)
+            ));
+            send(Message::Notification(Notification::new(
+                "exit".to_string(),
+                serde_json::Value::Null,
+            )));
+            handle.join().unwrap().unwrap();
+        }
+    }
+}
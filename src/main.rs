@@ -1,19 +1,42 @@
 mod ast;
+mod exit;
 
-use std::{borrow::Cow, path::Path};
+use std::{
+    borrow::Cow,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
 
-use changelog::{check, debug};
-use changelog_ast::Node;
-use clap::{Command, arg};
-use miette::{IntoDiagnostic, Result};
+use changelog::{
+    Baseline, BaselineError, Changelog, ChangelogCheckError, ChangelogDocument,
+    DEFAULT_FRAGMENTS_DIRECTORY, DebianConfig, DecodedText, FeedFormat, FeedMetadata,
+    FragmentError, HtmlLayout, Manifest, ManifestError, MemberCheckError, RpmConfig, check_package,
+    check_workspace, commit_messages, debug, diff, git_tags, import_debian, merge, read_fragments,
+    read_revision, remote_url, run_language_server, verify_pr,
+};
+#[cfg(feature = "serde")]
+use changelog::{DocumentFormat, import};
+#[cfg(feature = "schema")]
+use changelog::{export_schema, import_schema};
+use changelog_ast::{Encoding, LineIndex, Node};
+use chrono::{Local, NaiveDate};
+use clap::{ArgMatches, Command, arg};
+use miette::{IntoDiagnostic, miette};
 use ptree::{TreeItem, print_tree};
+use semver::Version;
+use tracing_subscriber::filter::LevelFilter;
 
-use crate::ast::{Ast, parse_ast};
+use crate::{
+    ast::{Ast, parse_ast},
+    exit::{ColorChoice, ExitStatus, ExitWith, Failure, paint},
+};
 
-fn main() -> Result<()> {
-    tracing_subscriber::fmt::init();
+type Result<T> = std::result::Result<T, Failure>;
+
+fn main() -> ExitStatus {
     let command = Command::new("rs-changelog")
         .author("El Pendeloco")
+        .subcommand_required(true)
         .subcommand(
             Command::new("ast")
                 .about("This program takes in a markdown file name and produces an AST.")
@@ -27,14 +50,265 @@ fn main() -> Result<()> {
         .subcommand(
             Command::new("check")
                 .about("This command checks the provided changelog.")
-                .arg(arg!(<file> "The mardkwon file to lint.")),
+                .arg(arg!(--fragments <directory> "Also validates the changelog fragments of this directory."))
+                .arg(arg!(--manifest <path> "Also verifies the version of this Cargo.toml, package.json, pyproject.toml, setup.cfg or VERSION agrees with the changelog."))
+                .arg(arg!(--repository <path> "Also cross-checks the releases against the tags of this git repository."))
+                .arg(arg!(--baseline <file> "Only fails on the lint violations not recorded in this baseline file."))
+                .arg(
+                    arg!(--"write-baseline" <file> "Records the lint violations in this baseline file instead of failing on them, skipping the manifest and repository cross-checks.")
+                        .conflicts_with("baseline"),
+                )
+                .arg(
+                    arg!(--workspace "Checks the changelogs of all the members of the cargo workspace of the current directory instead.")
+                        .conflicts_with_all(["files", "fragments", "manifest", "repository", "baseline", "write-baseline"]),
+                )
+                .arg(
+                    arg!([files]... "The markdown files or glob patterns to lint, - for stdin.")
+                        .required_unless_present("workspace"),
+                ),
+        )
+        .subcommand(
+            Command::new("init")
+                .about("This command writes a new changelog, with an empty unreleased section.")
+                .arg(arg!(--repo <url> "The URL of the repository, inferred from the git remotes of the current directory by default."))
+                .arg(arg!([file] "The markdown file to create.").default_value("CHANGELOG.md")),
+        )
+        .subcommand(
+            Command::new("collect")
+                .about("This command moves the changelog fragments into the unreleased section of the provided changelog.")
+                .arg(arg!(--dir <directory> "The fragments directory, relative to the changelog by default."))
+                .arg(arg!(<file> "The markdown file to update.")),
+        )
+        .subcommand(
+            Command::new("from-git")
+                .about("This command adds the conventional commits since the latest release to the unreleased section of the provided changelog.")
+                .arg(arg!(--since <revision> "The revision to start from, instead of the tag of the latest release."))
+                .arg(arg!(<file> "The markdown file to update.")),
+        )
+        .subcommand(
+            Command::new("release")
+                .about("This command releases the unreleased changes of the provided changelog, and bumps the version of its manifest.")
+                .arg(arg!(--version <version> "The version to release, inferred from the unreleased changes by default."))
+                .arg(arg!(--date <date> "The release date, as YYYY-MM-DD, today by default."))
+                .arg(arg!(--manifest <path> "The Cargo.toml, package.json, pyproject.toml, setup.cfg or VERSION to bump, the one next to the changelog by default."))
+                .arg(arg!(<file> "The markdown file to update.")),
+        )
+        .subcommand(
+            Command::new("diff")
+                .about("This command outputs the semantic differences between two revisions of a changelog.")
+                .arg(arg!(<old> "The markdown file of the old revision."))
+                .arg(arg!(<new> "The markdown file of the new revision.")),
+        )
+        .subcommand(
+            Command::new("verify-pr")
+                .about("This command verifies the changelog was updated according to policy since the base revision.")
+                .arg(arg!(--base <revision> "The git revision the pull request is based on.").required(true))
+                .arg(arg!(<file> "The markdown file to verify.")),
+        )
+        .subcommand(
+            Command::new("merge-driver")
+                .about("This command merges two revisions of a changelog, following git's merge driver protocol.")
+                .after_help("Register it with `git config merge.changelog.driver \"rs-changelog merge-driver %O %A %B\"` and `CHANGELOG.md merge=changelog` in .gitattributes.")
+                .arg(arg!(<base> "The common ancestor revision, %O."))
+                .arg(arg!(<ours> "Our revision, %A, overwritten with the merge result."))
+                .arg(arg!(<theirs> "Their revision, %B.")),
+        )
+        .subcommand(
+            Command::new("lsp")
+                .about("This command runs a language server for changelog files, speaking the Language Server Protocol over stdio."),
         )
-        .after_help("This program is a work in progress.");
-    let matches = command.get_matches();
+        .subcommand(
+            Command::new("html")
+                .about("This command renders the provided changelog as HTML.")
+                .arg(
+                    arg!(--layout <layout> "Whether to produce a standalone page or a fragment.")
+                        .value_parser(["page", "fragment"])
+                        .default_value("page"),
+                )
+                .arg(arg!(<file> "The markdown file to render.")),
+        )
+        .subcommand(
+            Command::new("feed")
+                .about("This command generates an Atom or RSS feed from the releases of the provided changelog.")
+                .arg(
+                    arg!(--format <format> "The feed format.")
+                        .value_parser(["atom", "rss"])
+                        .default_value("atom"),
+                )
+                .arg(arg!(--"base-url" <url> "The URL where the changelog is published.").required(true))
+                .arg(arg!(--title <title> "The title of the feed.").default_value("Changelog"))
+                .arg(arg!(--description <description> "The description of the feed."))
+                .arg(arg!(--author <author> "The author of the feed. RSS only credits it when it is an email address."))
+                .arg(arg!(<file> "The markdown file to publish.")),
+        )
+        .subcommand(
+            Command::new("debian")
+                .about("This command converts between changelogs and debian/changelog files.")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("export")
+                        .about("This command outputs the releases of the provided changelog as a debian/changelog.")
+                        .arg(arg!(--package <package> "The name of the source package.").required(true))
+                        .arg(arg!(--maintainer <maintainer> "The maintainer, as \"Full Name <email>\".").required(true))
+                        .arg(arg!(--distribution <distribution> "The target distribution.").default_value("unstable"))
+                        .arg(arg!(--urgency <urgency> "The upload urgency.").default_value("medium"))
+                        .arg(arg!(<file> "The markdown file to export.")),
+                )
+                .subcommand(
+                    Command::new("import")
+                        .about("This command generates a markdown changelog from a debian/changelog.")
+                        .arg(arg!(--repository <url> "The repository URL used for the reference definitions.").required(true))
+                        .arg(arg!(<file> "The debian/changelog file to import.")),
+                ),
+        )
+        .subcommand(
+            Command::new("rpm")
+                .about("This command outputs the releases of the provided changelog as an RPM %changelog section.")
+                .arg(arg!(--packager <packager> "The packager, as \"Full Name <email>\".").required(true))
+                .arg(arg!(--release <release> "The release of the spec file, appended to the versions."))
+                .arg(arg!(<file> "The markdown file to export.")),
+        )
+        .subcommand(
+            Command::new("appstream")
+                .about("This command outputs the releases of the provided changelog as AppStream metainfo.")
+                .arg(arg!(<file> "The markdown file to export.")),
+        );
+    #[cfg(feature = "serde")]
+    let command = command.subcommand(
+        Command::new("export")
+            .about("This command exports the parsed changelog in a structured format.")
+            .arg(
+                arg!(--format <format> "The output format.")
+                    .value_parser(["json", "yaml"])
+                    .default_value("json"),
+            )
+            .arg(arg!(<file> "The markdown file to export.")),
+    );
+    #[cfg(feature = "serde")]
+    let command = command.subcommand(
+        Command::new("import")
+            .about("This command generates a markdown changelog from a structured document.")
+            .arg(
+                arg!(--format <format> "The input format.")
+                    .value_parser(["json", "yaml"])
+                    .default_value("json"),
+            )
+            .arg(arg!(<file> "The structured document to import.")),
+    );
+    #[cfg(feature = "schema")]
+    let command = command.subcommand(
+        Command::new("schema")
+            .about("This command outputs the JSON schema of the structured documents.")
+            .arg(
+                arg!(--document <document> "The document to describe.")
+                    .value_parser(["export", "import"])
+                    .default_value("export"),
+            ),
+    );
+    let command = command
+        .arg(
+            arg!(--latin1 "Decodes input files that aren't valid UTF-8 as Latin-1 instead of failing.")
+                .global(true),
+        )
+        .arg(
+            arg!(-q --quiet "Only reports through the exit status, without printing progress or errors.")
+                .global(true)
+                .conflicts_with("verbose"),
+        )
+        .arg(arg!(-v --verbose... "Prints the causes of errors and more logs, repeat for even more.").global(true))
+        .arg(
+            arg!(--color <when> "When to color the output.")
+                .value_parser(["auto", "always", "never"])
+                .default_value("auto")
+                .global(true),
+        )
+        .after_help(format!("{}\n\nThis program is a work in progress.", ExitStatus::HELP));
+    let matches = match command.try_get_matches() {
+        Ok(matches) => matches,
+        // Help and version requests are reported as errors too, but go to stdout.
+        Err(err) => {
+            let _ = err.print();
+            return match err.use_stderr() {
+                true => ExitStatus::UsageError,
+                false => ExitStatus::Success,
+            };
+        }
+    };
+    let output = Output::from(&matches);
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_ansi(output.color.enabled(&std::io::stderr()))
+        .with_max_level(output.log_level())
+        .init();
+    match run(&matches, &output) {
+        Ok(()) => ExitStatus::Success,
+        Err(failure) => {
+            output.failure(&failure);
+            failure.status()
+        }
+    }
+}
+
+// How much to print, and whether to color it.
+struct Output {
+    quiet: bool,
+    verbosity: u8,
+    color: ColorChoice,
+}
+
+impl Output {
+    fn log_level(&self) -> LevelFilter {
+        match (self.quiet, self.verbosity) {
+            (true, _) => LevelFilter::OFF,
+            (false, 0) => LevelFilter::WARN,
+            (false, 1) => LevelFilter::INFO,
+            (false, 2) => LevelFilter::DEBUG,
+            (false, _) => LevelFilter::TRACE,
+        }
+    }
+
+    // The outcome of a single changelog, when checking several.
+    fn outcome(&self, success: bool, message: std::fmt::Arguments) {
+        if self.quiet {
+            return;
+        }
+        let label = format!("{:<5}", if success { "ok" } else { "error" });
+        let colored = self.color.enabled(&std::io::stdout());
+        println!("{} {}", paint(&label, success, colored), message);
+    }
+
+    fn failure(&self, failure: &Failure) {
+        if self.quiet {
+            return;
+        }
+        let colored = self.color.enabled(&std::io::stderr());
+        eprintln!("{}: {}", paint("error", false, colored), failure.report());
+        if self.verbosity > 0 {
+            for cause in failure.report().chain().skip(1) {
+                eprintln!("  caused by: {}", cause);
+            }
+        }
+    }
+}
+
+impl From<&ArgMatches> for Output {
+    fn from(matches: &ArgMatches) -> Self {
+        // The value parser guarantees the choice is known.
+        let color = matches.get_one::<String>("color").unwrap();
+        Self {
+            quiet: matches.get_flag("quiet"),
+            verbosity: matches.get_count("verbose"),
+            color: ColorChoice::try_from(color.as_str()).unwrap(),
+        }
+    }
+}
+
+fn run(matches: &ArgMatches, output: &Output) -> Result<()> {
+    let latin1 = matches.get_flag("latin1");
     match matches.subcommand() {
         Some(("ast", args)) => {
             let file = args.get_one::<String>("file").unwrap();
-            let content = read_file(file)?;
+            let content = read_file(file, latin1)?;
             let tree = parse_ast(&content);
             PrettyAst::from(&tree).pretty_print().into_diagnostic()?;
             for (key, value) in tree.reference_definitions.iter() {
@@ -43,22 +317,504 @@ fn main() -> Result<()> {
         }
         Some(("debug", args)) => {
             let file = args.get_one::<String>("file").unwrap();
-            let content = read_file(file)?;
+            let content = read_file(file, latin1)?;
             debug(&content);
         }
+        Some(("check", args)) if args.get_flag("workspace") => {
+            let checks = check_workspace(Path::new("Cargo.toml"), latin1)
+                .map_err(|err| manifest_failure(&err))?;
+            let mut failed = vec![];
+            for member in &checks {
+                let changelog = member.changelog().display();
+                match member.result() {
+                    Ok(()) => output.outcome(true, format_args!("{}", changelog)),
+                    Err(err) => {
+                        failed.push(match err {
+                            MemberCheckError::Read(_) => ExitStatus::IoFailure,
+                            MemberCheckError::Check(err) => check_status(err),
+                            MemberCheckError::Panicked => ExitStatus::Failure,
+                        });
+                        match member.position() {
+                            Some(position) => output.outcome(
+                                false,
+                                format_args!(
+                                    "{}:{}:{}: {}",
+                                    changelog, position.line, position.column, err
+                                ),
+                            ),
+                            None => output.outcome(false, format_args!("{}: {}", changelog, err)),
+                        }
+                    }
+                }
+            }
+            summarize(&failed, checks.len())?;
+        }
         Some(("check", args)) => {
+            let files = expand_files(args.get_many::<String>("files").unwrap())?;
+            let mut violations = match (
+                args.get_one::<String>("baseline"),
+                args.get_one::<String>("write-baseline"),
+            ) {
+                (Some(baseline), _) => Violations::Compare(
+                    Baseline::read(Path::new(baseline)).map_err(|err| baseline_failure(&err))?,
+                ),
+                (_, Some(_)) => Violations::Record(Baseline::default()),
+                (None, None) => Violations::Fail,
+            };
+            let checked = if let [file] = files.as_slice() {
+                check_file(file, args, latin1, &mut violations)
+            } else {
+                let mut failed = vec![];
+                for file in &files {
+                    match check_file(file, args, latin1, &mut violations) {
+                        Ok(()) => output.outcome(true, format_args!("{}", file)),
+                        Err(failure) => {
+                            failed.push(failure.status());
+                            output.outcome(false, format_args!("{}", failure.report()));
+                        }
+                    }
+                }
+                summarize(&failed, files.len())
+            };
+            // The violations of the files that could be checked are recorded regardless.
+            if let (Violations::Record(baseline), Some(path)) =
+                (&violations, args.get_one::<String>("write-baseline"))
+            {
+                baseline
+                    .write(Path::new(path))
+                    .exit_with(ExitStatus::IoFailure)?;
+            }
+            checked?;
+        }
+        Some(("init", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let repository = match args.get_one::<String>("repo") {
+                Some(repository) => repository.clone(),
+                None => remote_url(Path::new("."))
+                    .exit_with(ExitStatus::IoFailure)?
+                    .ok_or_else(|| {
+                        miette!("no git remote found, pass the repository with --repo")
+                    })?,
+            };
+            let content = ChangelogDocument::new(repository)
+                .to_markdown()
+                .into_diagnostic()?;
+            // An existing changelog is never overwritten.
+            std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(file)
+                .and_then(|mut changelog| changelog.write_all(content.as_bytes()))
+                .exit_with(ExitStatus::IoFailure)
+                .map_err(|failure| failure.prefixed(file))?;
+        }
+        Some(("collect", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let directory = match args.get_one::<String>("dir") {
+                Some(directory) => PathBuf::from(directory),
+                None => Path::new(file)
+                    .parent()
+                    .unwrap_or(Path::new(""))
+                    .join(DEFAULT_FRAGMENTS_DIRECTORY),
+            };
+            let content = read_text(file, latin1)?;
+            let changelog = Changelog::parse(content.text()).exit_with(ExitStatus::ParseFailure)?;
+            let fragments = read_fragments(&directory).map_err(|err| fragment_failure(&err))?;
+            write_text(file, &content, &changelog.collect_fragments(&fragments))?;
+            // Fragments are only deleted once their entries are safely in the changelog.
+            for fragment in &fragments {
+                std::fs::remove_file(fragment.path()).exit_with(ExitStatus::IoFailure)?;
+            }
+        }
+        Some(("from-git", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let content = read_text(file, latin1)?;
+            let changelog = Changelog::parse(content.text()).exit_with(ExitStatus::ParseFailure)?;
+            let since = match args.get_one::<String>("since") {
+                Some(revision) => Some(revision.clone()),
+                None => changelog
+                    .releases()
+                    .first()
+                    .map(|release| changelog.tag_scheme().release_tag(release.version())),
+            };
+            let messages = commit_messages(since.as_deref()).into_diagnostic()?;
+            let entries = changelog.entries_from_commits(&messages);
+            write_text(file, &content, &changelog.with_unreleased_entries(entries))?;
+        }
+        Some(("release", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let content = read_text(file, latin1)?;
+            let changelog = Changelog::parse(content.text()).exit_with(ExitStatus::ParseFailure)?;
+            let version = match args.get_one::<String>("version") {
+                Some(version) => Version::parse(version).exit_with(ExitStatus::UsageError)?,
+                None => changelog
+                    .next_version()
+                    .ok_or_else(|| miette!("no unreleased changes to release"))?,
+            };
+            let date = match args.get_one::<String>("date") {
+                Some(date) => {
+                    NaiveDate::parse_from_str(date, "%Y-%m-%d").exit_with(ExitStatus::UsageError)?
+                }
+                None => Local::now().date_naive(),
+            };
+            let manifest = match args.get_one::<String>("manifest") {
+                Some(manifest) => Some(
+                    Manifest::new(PathBuf::from(manifest)).map_err(|err| manifest_failure(&err))?,
+                ),
+                // A changelog piped through stdin has no manifest next to it.
+                None if file == "-" => None,
+                None => Manifest::adjacent(Path::new(file)),
+            };
+            let released = changelog.release(version.clone(), date).into_diagnostic()?;
+            write_text(file, &content, &released)?;
+            if let Some(manifest) = manifest {
+                manifest
+                    .set_version(&version)
+                    .map_err(|err| manifest_failure(&err).prefixed(manifest.path().display()))?;
+            }
+        }
+        Some(("diff", args)) => {
+            let old = read_file(args.get_one::<String>("old").unwrap(), latin1)?;
+            let new = read_file(args.get_one::<String>("new").unwrap(), latin1)?;
+            let old = Changelog::parse(&old).exit_with(ExitStatus::ParseFailure)?;
+            let new = Changelog::parse(&new).exit_with(ExitStatus::ParseFailure)?;
+            for difference in diff(&old, &new) {
+                println!("{}", difference);
+            }
+        }
+        Some(("verify-pr", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let revision = args.get_one::<String>("base").unwrap();
+            let base = read_revision(revision, Path::new(file)).into_diagnostic()?;
+            // Both sides are decoded the same way, in case the encoding changed in between.
+            let base = DecodedText::decode(&base, latin1)
+                .exit_with(ExitStatus::IoFailure)?
+                .into_text();
+            let head = read_file(file, latin1)?;
+            let base = Changelog::parse(&base).exit_with(ExitStatus::ParseFailure)?;
+            let package = package_name(Manifest::adjacent(Path::new(file)).as_ref());
+            let head = check_package(&head, package.as_deref())
+                .map_err(|err| located(file, &head, err))?;
+            verify_pr(&base, &head).exit_with(ExitStatus::LintFailure)?;
+        }
+        Some(("merge-driver", args)) => {
+            let ours_file = args.get_one::<String>("ours").unwrap();
+            let base = read_file(args.get_one::<String>("base").unwrap(), latin1)?;
+            let ours =
+                DecodedText::read(Path::new(ours_file), latin1).exit_with(ExitStatus::IoFailure)?;
+            let theirs = read_file(args.get_one::<String>("theirs").unwrap(), latin1)?;
+            let base = Changelog::parse(&base).exit_with(ExitStatus::ParseFailure)?;
+            let ours_changelog =
+                Changelog::parse(ours.text()).exit_with(ExitStatus::ParseFailure)?;
+            let theirs = Changelog::parse(&theirs).exit_with(ExitStatus::ParseFailure)?;
+            // On conflict, we exit with an error and leave our revision in place, which git
+            // then reports as conflicted.
+            let merged = merge(&base, &ours_changelog, &theirs).into_diagnostic()?;
+            ours.write(Path::new(ours_file), &merged)
+                .exit_with(ExitStatus::IoFailure)?;
+        }
+        Some(("lsp", _)) => {
+            run_language_server().into_diagnostic()?;
+        }
+        Some(("html", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let layout = args.get_one::<String>("layout").unwrap();
+            let content = read_file(file, latin1)?;
+            let changelog = Changelog::parse(&content).exit_with(ExitStatus::ParseFailure)?;
+            // The value parser guarantees the layout is known.
+            let layout = HtmlLayout::try_from(layout.as_str()).unwrap();
+            print!("{}", changelog.to_html(layout));
+        }
+        Some(("feed", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let format = args.get_one::<String>("format").unwrap();
+            let content = read_file(file, latin1)?;
+            let changelog = Changelog::parse(&content).exit_with(ExitStatus::ParseFailure)?;
+            let format = FeedFormat::try_from(format.as_str()).unwrap();
+            let mut metadata = FeedMetadata::new(
+                args.get_one::<String>("title").unwrap().clone(),
+                args.get_one::<String>("base-url").unwrap().clone(),
+            );
+            if let Some(description) = args.get_one::<String>("description") {
+                metadata = metadata.with_description(description.clone());
+            }
+            if let Some(author) = args.get_one::<String>("author") {
+                metadata = metadata.with_author(author.clone());
+            }
+            print!("{}", changelog.to_feed(format, &metadata));
+        }
+        Some(("debian", args)) => match args.subcommand() {
+            Some(("export", args)) => {
+                let file = args.get_one::<String>("file").unwrap();
+                let content = read_file(file, latin1)?;
+                let changelog = Changelog::parse(&content).exit_with(ExitStatus::ParseFailure)?;
+                let config = DebianConfig::new(
+                    args.get_one::<String>("package").unwrap().clone(),
+                    args.get_one::<String>("maintainer").unwrap().clone(),
+                )
+                .with_distribution(args.get_one::<String>("distribution").unwrap().clone())
+                .with_urgency(args.get_one::<String>("urgency").unwrap().clone());
+                print!("{}", changelog.to_debian(&config));
+            }
+            Some(("import", args)) => {
+                let file = args.get_one::<String>("file").unwrap();
+                let repository = args.get_one::<String>("repository").unwrap();
+                let content = read_file(file, latin1)?;
+                print!("{}", import_debian(&content, repository).into_diagnostic()?);
+            }
+            // Subcommands are required, and clap rejects the unknown ones.
+            _ => unreachable!(),
+        },
+        Some(("rpm", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let content = read_file(file, latin1)?;
+            let changelog = Changelog::parse(&content).exit_with(ExitStatus::ParseFailure)?;
+            let mut config = RpmConfig::new(args.get_one::<String>("packager").unwrap().clone());
+            if let Some(release) = args.get_one::<String>("release") {
+                config = config.with_release(release.clone());
+            }
+            print!("{}", changelog.to_rpm_changelog(&config));
+        }
+        Some(("appstream", args)) => {
             let file = args.get_one::<String>("file").unwrap();
-            let content = read_file(file)?;
-            check(&content).into_diagnostic()?;
+            let content = read_file(file, latin1)?;
+            let changelog = Changelog::parse(&content).exit_with(ExitStatus::ParseFailure)?;
+            print!("{}", changelog.to_appstream());
         }
-        Some((unknown, _)) => panic!("unknown subcommand: {}", unknown),
-        None => panic!("unexpected lack of subcommand"),
+        #[cfg(feature = "serde")]
+        Some(("export", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let format = args.get_one::<String>("format").unwrap();
+            let content = read_file(file, latin1)?;
+            let changelog = Changelog::parse(&content).exit_with(ExitStatus::ParseFailure)?;
+            // The value parser guarantees the format is known.
+            let format = DocumentFormat::try_from(format.as_str()).unwrap();
+            println!("{}", changelog.export(format).into_diagnostic()?);
+        }
+        #[cfg(feature = "serde")]
+        Some(("import", args)) => {
+            let file = args.get_one::<String>("file").unwrap();
+            let format = args.get_one::<String>("format").unwrap();
+            let content = read_file(file, latin1)?;
+            let format = DocumentFormat::try_from(format.as_str()).unwrap();
+            print!("{}", import(&content, format).into_diagnostic()?);
+        }
+        #[cfg(feature = "schema")]
+        Some(("schema", args)) => {
+            let schema = match args.get_one::<String>("document").unwrap().as_str() {
+                "import" => import_schema(),
+                _ => export_schema(),
+            };
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&schema).into_diagnostic()?
+            );
+        }
+        _ => unreachable!(),
     };
     Ok(())
 }
 
-fn read_file<P: AsRef<Path>>(path: P) -> Result<String> {
-    std::fs::read_to_string(&path).into_diagnostic()
+// The package name is only needed for monorepo tags, so an unreadable one is no reason to
+// fail yet.
+fn package_name(manifest: Option<&Manifest>) -> Option<String> {
+    manifest
+        .and_then(|manifest| manifest.package_name().ok())
+        .flatten()
+}
+
+fn read_file<P: AsRef<Path>>(path: P, latin1: bool) -> Result<String> {
+    Ok(read_text(path, latin1)?.into_text())
+}
+
+// Reads stdin for `-`. Files may be encoded in UTF-16 or have a byte order mark.
+fn read_text<P: AsRef<Path>>(path: P, latin1: bool) -> Result<DecodedText> {
+    let path = path.as_ref();
+    let bytes = if path == Path::new("-") {
+        let mut bytes = vec![];
+        std::io::stdin()
+            .read_to_end(&mut bytes)
+            .exit_with(ExitStatus::IoFailure)?;
+        bytes
+    } else {
+        std::fs::read(path).exit_with(ExitStatus::IoFailure)?
+    };
+    DecodedText::decode(&bytes, latin1).exit_with(ExitStatus::IoFailure)
+}
+
+// Writes stdout for `-`, encoded like the text read.
+fn write_text(path: &str, decoded: &DecodedText, text: &str) -> Result<()> {
+    if path == "-" {
+        std::io::stdout()
+            .write_all(&decoded.encode(text))
+            .exit_with(ExitStatus::IoFailure)
+    } else {
+        decoded
+            .write(Path::new(path), text)
+            .exit_with(ExitStatus::IoFailure)
+    }
+}
+
+// Expands the patterns with wildcards, in order. `-` and plain paths are kept as is.
+fn expand_files<'a>(patterns: impl Iterator<Item = &'a String>) -> Result<Vec<String>> {
+    let mut files = vec![];
+    for pattern in patterns {
+        if pattern == "-" || !pattern.contains(['*', '?', '[']) {
+            files.push(pattern.clone());
+            continue;
+        }
+        let mut matched: Vec<String> = glob::glob(pattern)
+            .into_diagnostic()?
+            .filter_map(|path| path.ok())
+            .map(|path| path.display().to_string())
+            .collect();
+        if matched.is_empty() {
+            return Err(Failure::new(
+                ExitStatus::IoFailure,
+                miette!("no file matches {}", pattern),
+            ));
+        }
+        matched.sort();
+        files.extend(matched);
+    }
+    Ok(files)
+}
+
+// Checks a single changelog, with errors prefixed by the file.
+fn check_file(
+    file: &str,
+    args: &ArgMatches,
+    latin1: bool,
+    violations: &mut Violations,
+) -> Result<()> {
+    let in_file = |failure: Failure| failure.prefixed(file);
+    let content = read_file(file, latin1).map_err(in_file)?;
+    let manifest = match args.get_one::<String>("manifest") {
+        Some(manifest) => Some(
+            Manifest::new(PathBuf::from(manifest))
+                .map_err(|err| in_file(manifest_failure(&err)))?,
+        ),
+        None => Manifest::adjacent(Path::new(file)),
+    };
+    let package = package_name(manifest.as_ref());
+    // The baseline only holds the violations of the changelog lints, so recording skips the
+    // cross-checks.
+    let cross_check = !matches!(violations, Violations::Record(_));
+    let changelog = match violations {
+        Violations::Fail => check_package(&content, package.as_deref())
+            .map_err(|err| located(file, &content, err))?,
+        Violations::Compare(baseline) => {
+            let changelog =
+                Changelog::parse(&content).map_err(|err| located(file, &content, err.into()))?;
+            let errors = changelog.lint_violations(package.as_deref());
+            if let Some(err) = baseline
+                .unrecorded(file, &changelog, errors)
+                .into_iter()
+                .next()
+            {
+                return Err(located(file, &content, err.into()));
+            }
+            changelog
+        }
+        Violations::Record(baseline) => {
+            let changelog =
+                Changelog::parse(&content).map_err(|err| located(file, &content, err.into()))?;
+            baseline.record(
+                file,
+                &changelog,
+                &changelog.lint_violations(package.as_deref()),
+            );
+            changelog
+        }
+    };
+    if let Some(directory) = args.get_one::<String>("fragments") {
+        read_fragments(Path::new(directory)).map_err(|err| in_file(fragment_failure(&err)))?;
+    }
+    if let Some(manifest) = manifest.filter(|_| cross_check && args.contains_id("manifest")) {
+        let version = manifest
+            .version()
+            .map_err(|err| in_file(manifest_failure(&err)))?;
+        changelog
+            .lint_manifest_version(&version)
+            .exit_with(ExitStatus::LintFailure)
+            .map_err(in_file)?;
+    }
+    if let Some(repository) = args.get_one::<String>("repository").filter(|_| cross_check) {
+        let tags = git_tags(Path::new(repository))
+            .exit_with(ExitStatus::Failure)
+            .map_err(in_file)?;
+        changelog
+            .lint_tags(&tags)
+            .exit_with(ExitStatus::LintFailure)
+            .map_err(in_file)?;
+    }
+    Ok(())
+}
+
+// What to do with the lint violations of the checked changelogs.
+enum Violations {
+    Fail,
+    // Only fails on those not recorded in the baseline.
+    Compare(Baseline),
+    // Records them in the baseline instead of failing.
+    Record(Baseline),
+}
+
+// Prefixes the error with the line and column it was found at, as compilers do.
+fn located(file: &str, source: &str, err: ChangelogCheckError) -> Failure {
+    let status = check_status(&err);
+    match err.range(source) {
+        Some(range) => {
+            let position = LineIndex::new(source).line_column(range.start, Encoding::Utf8);
+            Failure::new(
+                status,
+                miette!("{}:{}:{}: {}", file, position.line, position.column, err),
+            )
+        }
+        None => Failure::new(status, miette!("{}: {}", file, err)),
+    }
+}
+
+fn check_status(err: &ChangelogCheckError) -> ExitStatus {
+    match err {
+        ChangelogCheckError::ParseError(_) => ExitStatus::ParseFailure,
+        ChangelogCheckError::LintError(_) => ExitStatus::LintFailure,
+    }
+}
+
+fn manifest_failure(err: &ManifestError) -> Failure {
+    let status = match err {
+        ManifestError::Io(_) => ExitStatus::IoFailure,
+        _ => ExitStatus::Failure,
+    };
+    Failure::new(status, miette!("{}", err))
+}
+
+fn baseline_failure(err: &BaselineError) -> Failure {
+    let status = match err {
+        BaselineError::Io(_) => ExitStatus::IoFailure,
+        _ => ExitStatus::Failure,
+    };
+    Failure::new(status, miette!("{}", err))
+}
+
+fn fragment_failure(err: &FragmentError) -> Failure {
+    let status = match err {
+        FragmentError::Io(_) => ExitStatus::IoFailure,
+        _ => ExitStatus::ParseFailure,
+    };
+    Failure::new(status, miette!("{}", err))
+}
+
+// Fails with the most severe status of the changelogs that failed the check, if any.
+fn summarize(failed: &[ExitStatus], total: usize) -> Result<()> {
+    match failed.iter().max() {
+        Some(status) => Err(Failure::new(
+            *status,
+            miette!("{} of {} changelogs failed the check", failed.len(), total),
+        )),
+        None => Ok(()),
+    }
 }
 
 #[derive(Clone)]
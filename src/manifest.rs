@@ -0,0 +1,602 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use semver::Version;
+use toml::{Table, Value};
+
+/// The kinds of package manifests a version can be read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestKind {
+    /// A Rust `Cargo.toml`, possibly inheriting its version from its workspace.
+    Cargo,
+    /// A JavaScript `package.json`.
+    PackageJson,
+    /// A Python `pyproject.toml`, either standard or Poetry flavored.
+    PyProject,
+    /// A Python `setup.cfg`.
+    SetupCfg,
+    /// A plain `VERSION` file, holding nothing but the version.
+    VersionFile,
+}
+
+impl ManifestKind {
+    /// Detects the kind of a manifest from its file name.
+    pub fn detect(path: &Path) -> Option<Self> {
+        match path.file_name()?.to_str()? {
+            "Cargo.toml" => Some(ManifestKind::Cargo),
+            "package.json" => Some(ManifestKind::PackageJson),
+            "pyproject.toml" => Some(ManifestKind::PyProject),
+            "setup.cfg" => Some(ManifestKind::SetupCfg),
+            "VERSION" => Some(ManifestKind::VersionFile),
+            _ => None,
+        }
+    }
+}
+
+/// A package manifest declaring the version the changelog documents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifest {
+    path: PathBuf,
+    kind: ManifestKind,
+}
+
+// Where the version of a manifest is declared. The section is only meaningful for TOML and
+// INI files.
+struct Declaration {
+    path: PathBuf,
+    section: &'static str,
+    version: String,
+}
+
+impl Manifest {
+    pub fn new(path: PathBuf) -> Result<Self, ManifestError> {
+        match ManifestKind::detect(&path) {
+            Some(kind) => Ok(Self { path, kind }),
+            None => Err(ManifestError::UnknownKind(path)),
+        }
+    }
+
+    /// Finds the manifest next to a changelog, if any.
+    pub fn adjacent(changelog: &Path) -> Option<Self> {
+        let directory = changelog.parent().unwrap_or(Path::new(""));
+        [
+            "Cargo.toml",
+            "package.json",
+            "pyproject.toml",
+            "setup.cfg",
+            "VERSION",
+        ]
+        .into_iter()
+        .map(|name| directory.join(name))
+        .find(|path| path.is_file())
+        .and_then(|path| Self::new(path).ok())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn kind(&self) -> ManifestKind {
+        self.kind
+    }
+
+    /// Reads the version of the package.
+    ///
+    /// When a `Cargo.toml` inherits its version with `version.workspace = true`, it is read
+    /// from the `[workspace.package]` table of the closest enclosing workspace manifest instead.
+    pub fn version(&self) -> Result<Version, ManifestError> {
+        let declaration = self.declaration()?;
+        Version::parse(&declaration.version)
+            .map_err(|err| ManifestError::InvalidVersion(declaration.path, err))
+    }
+
+    /// Bumps the version of the package, in place.
+    ///
+    /// Only the version is touched, the rest of the file is left as is. Inherited versions are
+    /// bumped in the workspace manifest.
+    pub fn set_version(&self, version: &Version) -> Result<(), ManifestError> {
+        let declaration = self.declaration()?;
+        let content = std::fs::read_to_string(&declaration.path)?;
+        let new_version = version.to_string();
+        let updated = match self.kind {
+            ManifestKind::VersionFile => Some(format!("{}\n", new_version)),
+            ManifestKind::PackageJson => json_key_end(&content, "version").and_then(|start| {
+                replace_after(&content, start, &declaration.version, &new_version)
+            }),
+            ManifestKind::Cargo | ManifestKind::PyProject | ManifestKind::SetupCfg => {
+                section_value_start(&content, declaration.section, "version").and_then(|start| {
+                    replace_after(&content, start, &declaration.version, &new_version)
+                })
+            }
+        };
+        match updated {
+            Some(updated) => Ok(std::fs::write(&declaration.path, updated)?),
+            None => Err(ManifestError::MissingVersion(declaration.path)),
+        }
+    }
+
+    /// Reads the name of the package. `VERSION` files don't have any.
+    pub fn package_name(&self) -> Result<Option<String>, ManifestError> {
+        let name = match self.kind {
+            ManifestKind::Cargo => {
+                table_value(&read_toml(&self.path)?, &["package", "name"]).cloned()
+            }
+            ManifestKind::PyProject => {
+                let manifest = read_toml(&self.path)?;
+                table_value(&manifest, &["project", "name"])
+                    .or_else(|| table_value(&manifest, &["tool", "poetry", "name"]))
+                    .cloned()
+            }
+            ManifestKind::PackageJson => {
+                let content = std::fs::read_to_string(&self.path)?;
+                let manifest: serde_json::Value =
+                    serde_json::from_str(&content).map_err(|err| {
+                        ManifestError::InvalidContent(self.path.clone(), Box::new(err))
+                    })?;
+                return Ok(manifest
+                    .get("name")
+                    .and_then(|name| name.as_str())
+                    .map(str::to_string));
+            }
+            ManifestKind::SetupCfg => {
+                let content = std::fs::read_to_string(&self.path)?;
+                return Ok(
+                    section_value_start(&content, "metadata", "name").map(|start| {
+                        content[start..]
+                            .lines()
+                            .next()
+                            .unwrap_or_default()
+                            .trim()
+                            .to_string()
+                    }),
+                );
+            }
+            ManifestKind::VersionFile => None,
+        };
+        Ok(name.and_then(|name| name.as_str().map(str::to_string)))
+    }
+
+    fn declaration(&self) -> Result<Declaration, ManifestError> {
+        let declaration = |section, version: &str| Declaration {
+            path: self.path.clone(),
+            section,
+            version: version.to_string(),
+        };
+        match self.kind {
+            ManifestKind::Cargo => {
+                let manifest = read_toml(&self.path)?;
+                match table_value(&manifest, &["package", "version"]) {
+                    Some(Value::String(version)) => Ok(declaration("package", version)),
+                    Some(Value::Table(version))
+                        if version.get("workspace") == Some(&Value::Boolean(true)) =>
+                    {
+                        workspace_declaration(&self.path)
+                    }
+                    _ => Err(ManifestError::MissingVersion(self.path.clone())),
+                }
+            }
+            ManifestKind::PackageJson => {
+                let content = std::fs::read_to_string(&self.path)?;
+                let manifest: serde_json::Value =
+                    serde_json::from_str(&content).map_err(|err| {
+                        ManifestError::InvalidContent(self.path.clone(), Box::new(err))
+                    })?;
+                match manifest.get("version").and_then(|version| version.as_str()) {
+                    Some(version) => Ok(declaration("", version)),
+                    None => Err(ManifestError::MissingVersion(self.path.clone())),
+                }
+            }
+            ManifestKind::PyProject => {
+                let manifest = read_toml(&self.path)?;
+                if let Some(Value::String(version)) =
+                    table_value(&manifest, &["project", "version"])
+                {
+                    Ok(declaration("project", version))
+                } else if let Some(Value::String(version)) =
+                    table_value(&manifest, &["tool", "poetry", "version"])
+                {
+                    Ok(declaration("tool.poetry", version))
+                } else {
+                    // Dynamic versions are computed by the build backend.
+                    Err(ManifestError::MissingVersion(self.path.clone()))
+                }
+            }
+            ManifestKind::SetupCfg => {
+                let content = std::fs::read_to_string(&self.path)?;
+                match section_value_start(&content, "metadata", "version") {
+                    Some(start) => Ok(declaration(
+                        "metadata",
+                        content[start..].lines().next().unwrap_or_default().trim(),
+                    )),
+                    None => Err(ManifestError::MissingVersion(self.path.clone())),
+                }
+            }
+            ManifestKind::VersionFile => {
+                let content = std::fs::read_to_string(&self.path)?;
+                match content.trim() {
+                    "" => Err(ManifestError::MissingVersion(self.path.clone())),
+                    version => Ok(declaration("", version)),
+                }
+            }
+        }
+    }
+}
+
+fn workspace_declaration(path: &Path) -> Result<Declaration, ManifestError> {
+    let path = std::path::absolute(path)?;
+    // The package may also be the root of its own workspace.
+    for directory in path.ancestors().skip(1) {
+        let candidate = directory.join("Cargo.toml");
+        if !candidate.is_file() {
+            continue;
+        }
+        let manifest = read_toml(&candidate)?;
+        if manifest.get("workspace").is_none() {
+            continue;
+        }
+        return match table_value(&manifest, &["workspace", "package", "version"]) {
+            Some(Value::String(version)) => Ok(Declaration {
+                version: version.clone(),
+                path: candidate,
+                section: "workspace.package",
+            }),
+            _ => Err(ManifestError::MissingVersion(candidate)),
+        };
+    }
+    Err(ManifestError::MissingWorkspace(path))
+}
+
+fn read_toml(path: &Path) -> Result<Table, ManifestError> {
+    let content = std::fs::read_to_string(path)?;
+    content
+        .parse()
+        .map_err(|err| ManifestError::InvalidContent(path.to_path_buf(), Box::new(err)))
+}
+
+fn table_value<'table>(table: &'table Table, keys: &[&str]) -> Option<&'table Value> {
+    let (last, tables) = keys.split_last()?;
+    let mut table = table;
+    for key in tables {
+        table = table.get(*key)?.as_table()?;
+    }
+    table.get(*last)
+}
+
+// The offset of the value of a `key = value` line in a `[section]` of a TOML or INI file.
+fn section_value_start(content: &str, section: &str, key: &str) -> Option<usize> {
+    let mut current = "";
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            current = trimmed.trim_start_matches('[').trim_end_matches(']').trim();
+        } else if current == section
+            && let Some((name, _)) = line.split_once('=')
+            && name.trim() == key
+        {
+            return Some(offset + name.len() + 1);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+// The offset right after a key of the top level object. Nested objects, like dependencies or
+// configurations, may hold the same key.
+fn json_key_end(content: &str, key: &str) -> Option<usize> {
+    let quoted = format!("\"{}\"", key);
+    let mut depth = 0;
+    let mut chars = content.char_indices();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth -= 1,
+            '"' => {
+                let mut end = content.len();
+                while let Some((index, c)) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => {
+                            end = index + 1;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                if depth == 1
+                    && content[start..end] == quoted
+                    && content[end..].trim_start().starts_with(':')
+                {
+                    return Some(end);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// Replaces the first occurrence of `old` from the given offset.
+fn replace_after(content: &str, start: usize, old: &str, new: &str) -> Option<String> {
+    let position = start + content[start..].find(old)?;
+    let mut updated = content.to_string();
+    updated.replace_range(position..position + old.len(), new);
+    Some(updated)
+}
+
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(std::io::Error),
+    UnknownKind(PathBuf),
+    InvalidContent(PathBuf, Box<dyn Error + Send + Sync>),
+    MissingVersion(PathBuf),
+    MissingWorkspace(PathBuf),
+    InvalidVersion(PathBuf, semver::Error),
+}
+
+impl From<std::io::Error> for ManifestError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManifestError::Io(err) => write!(f, "unable to read manifest: {}", err),
+            ManifestError::UnknownKind(path) => write!(
+                f,
+                "expected manifest {} to be one of Cargo.toml, package.json, pyproject.toml, setup.cfg or VERSION",
+                path.display()
+            ),
+            ManifestError::InvalidContent(path, err) => {
+                write!(f, "unable to parse manifest {}: {}", path.display(), err)
+            }
+            ManifestError::MissingVersion(path) => {
+                write!(f, "manifest {} doesn't declare a version", path.display())
+            }
+            ManifestError::MissingWorkspace(path) => write!(
+                f,
+                "manifest {} inherits its version from a workspace that couldn't be found",
+                path.display()
+            ),
+            ManifestError::InvalidVersion(path, err) => {
+                write!(f, "invalid version in manifest {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl Error for ManifestError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Each test gets its own directory, as they run concurrently.
+    fn write_manifests(test: &str, manifests: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("changelog-manifest-{}", test));
+        let _ = std::fs::remove_dir_all(&root);
+        for (path, content) in manifests {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        root
+    }
+
+    fn read_version(path: PathBuf) -> Result<Version, ManifestError> {
+        Manifest::new(path)?.version()
+    }
+
+    mod version {
+        use super::*;
+
+        #[test]
+        fn should_read_cargo_version() {
+            let root = write_manifests(
+                "cargo",
+                &[(
+                    "Cargo.toml",
+                    "[package]\nname = \"bull\"\nversion = \"1.2.3\"\n",
+                )],
+            );
+            assert_eq!(
+                read_version(root.join("Cargo.toml")).unwrap(),
+                Version::new(1, 2, 3)
+            );
+        }
+
+        #[test]
+        fn should_read_workspace_inherited_version() {
+            let root = write_manifests(
+                "workspace",
+                &[
+                    (
+                        "Cargo.toml",
+                        "[workspace]\nmembers = [\"crates/bull\"]\n\n[workspace.package]\nversion = \"0.4.0\"\n",
+                    ),
+                    (
+                        "crates/bull/Cargo.toml",
+                        "[package]\nname = \"bull\"\nversion.workspace = true\n",
+                    ),
+                ],
+            );
+            assert_eq!(
+                read_version(root.join("crates/bull/Cargo.toml")).unwrap(),
+                Version::new(0, 4, 0)
+            );
+        }
+
+        #[test]
+        fn should_read_package_json_version() {
+            let root = write_manifests(
+                "package-json",
+                &[(
+                    "package.json",
+                    "{\n  \"name\": \"bull\",\n  \"version\": \"2.0.0-rc.1\"\n}\n",
+                )],
+            );
+            assert_eq!(
+                read_version(root.join("package.json")).unwrap(),
+                Version::parse("2.0.0-rc.1").unwrap()
+            );
+        }
+
+        #[test]
+        fn should_read_pyproject_versions() {
+            let root = write_manifests(
+                "pyproject",
+                &[
+                    (
+                        "standard/pyproject.toml",
+                        "[project]\nname = \"bull\"\nversion = \"0.1.0\"\n",
+                    ),
+                    (
+                        "poetry/pyproject.toml",
+                        "[tool.poetry]\nname = \"bull\"\nversion = \"0.2.0\"\n",
+                    ),
+                ],
+            );
+            assert_eq!(
+                read_version(root.join("standard/pyproject.toml")).unwrap(),
+                Version::new(0, 1, 0)
+            );
+            assert_eq!(
+                read_version(root.join("poetry/pyproject.toml")).unwrap(),
+                Version::new(0, 2, 0)
+            );
+        }
+
+        #[test]
+        fn should_read_setup_cfg_and_version_file() {
+            let root = write_manifests(
+                "python",
+                &[
+                    (
+                        "setup.cfg",
+                        "[options]\nversion = 9.9.9\n\n[metadata]\nname = bull\nversion = 1.0.1\n",
+                    ),
+                    ("VERSION", "1.0.2\n"),
+                ],
+            );
+            assert_eq!(
+                read_version(root.join("setup.cfg")).unwrap(),
+                Version::new(1, 0, 1)
+            );
+            assert_eq!(
+                read_version(root.join("VERSION")).unwrap(),
+                Version::new(1, 0, 2)
+            );
+        }
+
+        #[test]
+        fn should_error_without_version() {
+            let root =
+                write_manifests("missing", &[("Cargo.toml", "[package]\nname = \"bull\"\n")]);
+            let result = read_version(root.join("Cargo.toml"));
+            assert!(
+                matches!(result, Err(ManifestError::MissingVersion(_))),
+                "{:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn should_read_package_names() {
+            let root = write_manifests(
+                "names",
+                &[
+                    (
+                        "cargo/Cargo.toml",
+                        "[package]\nname = \"bull\"\nversion = \"1.2.3\"\n",
+                    ),
+                    (
+                        "npm/package.json",
+                        "{\"name\": \"@owner/bull\", \"version\": \"1.2.3\"}",
+                    ),
+                    ("plain/VERSION", "1.2.3\n"),
+                ],
+            );
+            let name = |path: &str| {
+                Manifest::adjacent(&root.join(path))
+                    .unwrap()
+                    .package_name()
+                    .unwrap()
+            };
+            assert_eq!(name("cargo/CHANGELOG.md"), Some("bull".to_string()));
+            assert_eq!(name("npm/CHANGELOG.md"), Some("@owner/bull".to_string()));
+            assert_eq!(name("plain/CHANGELOG.md"), None);
+        }
+
+        #[test]
+        fn should_error_with_unknown_kind() {
+            let result = read_version("build.gradle".into());
+            assert!(
+                matches!(result, Err(ManifestError::UnknownKind(_))),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    mod set_version {
+        use super::*;
+
+        #[test]
+        fn should_only_touch_the_version() {
+            let root = write_manifests(
+                "set-cargo",
+                &[(
+                    "Cargo.toml",
+                    "[package]\nname = \"bull\"\nversion = \"1.2.3\" # Keep me.\n\n[dependencies]\nbull = { version = \"1.2.3\" }\n",
+                )],
+            );
+            let manifest = Manifest::new(root.join("Cargo.toml")).unwrap();
+            manifest.set_version(&Version::new(1, 3, 0)).unwrap();
+            assert_eq!(
+                std::fs::read_to_string(manifest.path()).unwrap(),
+                "[package]\nname = \"bull\"\nversion = \"1.3.0\" # Keep me.\n\n[dependencies]\nbull = { version = \"1.2.3\" }\n"
+            );
+        }
+
+        #[test]
+        fn should_bump_package_json() {
+            let root = write_manifests(
+                "set-package-json",
+                &[(
+                    "package.json",
+                    "{\n  \"name\": \"bull\",\n  \"version\": \"1.2.3\",\n  \"dependencies\": {}\n}\n",
+                )],
+            );
+            let manifest = Manifest::new(root.join("package.json")).unwrap();
+            manifest.set_version(&Version::new(2, 0, 0)).unwrap();
+            assert_eq!(
+                std::fs::read_to_string(manifest.path()).unwrap(),
+                "{\n  \"name\": \"bull\",\n  \"version\": \"2.0.0\",\n  \"dependencies\": {}\n}\n"
+            );
+        }
+
+        #[test]
+        fn should_bump_top_level_version_of_package_json() {
+            let root = write_manifests(
+                "set-package-json-nested",
+                &[(
+                    "package.json",
+                    "{\n  \"description\": \"The \\\"version\\\": 1.2.3 bull\",\n  \"config\": { \"version\": \"1.2.3\" },\n  \"version\": \"1.2.3\"\n}\n",
+                )],
+            );
+            let manifest = Manifest::new(root.join("package.json")).unwrap();
+            manifest.set_version(&Version::new(2, 0, 0)).unwrap();
+            assert_eq!(
+                std::fs::read_to_string(manifest.path()).unwrap(),
+                "{\n  \"description\": \"The \\\"version\\\": 1.2.3 bull\",\n  \"config\": { \"version\": \"1.2.3\" },\n  \"version\": \"2.0.0\"\n}\n"
+            );
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use std::{collections::BTreeMap, error::Error, fmt::Display};
+
+use changelog_ast::CowStr;
+
+use crate::{
+    ChangeSetKind, Changelog, Changes, ReferenceDefinition, Release, Unreleased, UnreleasedHeading,
+    import::to_changes, lint::ref_def_linters::RefDefLinter,
+};
+
+/// Merges two revisions of a changelog that diverged from a common ancestor, like git would.
+///
+/// The unreleased entries are merged per change set kind: the entries added on either side
+/// are kept, and the entries removed on either side are dropped. The released sections, on
+/// the other hand, are only merged when at most one side changed them. The result is rendered
+/// in canonical form.
+pub fn merge(
+    base: &Changelog,
+    ours: &Changelog,
+    theirs: &Changelog,
+) -> Result<String, MergeConflict> {
+    let title = if ours.title().intro() == theirs.title().intro()
+        || base.title().intro() == theirs.title().intro()
+    {
+        ours.title()
+    } else if base.title().intro() == ours.title().intro() {
+        theirs.title()
+    } else {
+        return Err(MergeConflict::DivergentIntro);
+    };
+
+    let side = if same_releases(ours.releases(), theirs.releases())
+        || same_releases(base.releases(), theirs.releases())
+    {
+        ours
+    } else if same_releases(base.releases(), ours.releases()) {
+        theirs
+    } else {
+        return Err(MergeConflict::DivergentReleases);
+    };
+    let releases = side.releases().to_vec();
+
+    let unreleased = unreleased_section(
+        merge_unreleased(base, ours, theirs),
+        side.unreleased().is_some() || releases.is_empty(),
+    );
+    let reference_definitions = reference_definitions(side, unreleased.is_some());
+    let merged = Changelog::new(
+        "",
+        title.clone(),
+        unreleased,
+        releases,
+        reference_definitions,
+    );
+    Ok(merged.to_markdown())
+}
+
+fn same_releases(left: &[Release], right: &[Release]) -> bool {
+    left.len() == right.len()
+        && left.iter().zip(right).all(|(left, right)| {
+            left.version() == right.version()
+                && left.date() == right.date()
+                && left.is_yanked() == right.is_yanked()
+                && entries(left.changes()) == entries(right.changes())
+        })
+}
+
+pub(crate) fn entries(changes: &Changes) -> BTreeMap<ChangeSetKind, Vec<String>> {
+    changes
+        .iter()
+        .map(|change_set| {
+            let texts = change_set
+                .items()
+                .iter()
+                .map(|change| change.text().to_string())
+                .collect();
+            (change_set.kind(), texts)
+        })
+        .collect()
+}
+
+pub(crate) fn unreleased_entries(changelog: &Changelog) -> BTreeMap<ChangeSetKind, Vec<String>> {
+    changelog
+        .unreleased()
+        .as_ref()
+        .map(|unreleased| entries(unreleased.changes()))
+        .unwrap_or_default()
+}
+
+fn merge_unreleased(
+    base: &Changelog,
+    ours: &Changelog,
+    theirs: &Changelog,
+) -> BTreeMap<ChangeSetKind, Vec<String>> {
+    let base = unreleased_entries(base);
+    let theirs = unreleased_entries(theirs);
+    let mut merged = unreleased_entries(ours);
+    for (kind, texts) in &theirs {
+        let base_texts = base.get(kind);
+        let merged_texts = merged.entry(*kind).or_default();
+        for text in texts {
+            let in_base = base_texts.is_some_and(|base_texts| base_texts.contains(text));
+            if !in_base && !merged_texts.contains(text) {
+                merged_texts.push(text.clone());
+            }
+        }
+    }
+    // Drop what they removed. What we removed is already gone.
+    for (kind, base_texts) in &base {
+        let theirs_texts = theirs.get(kind);
+        if let Some(merged_texts) = merged.get_mut(kind) {
+            merged_texts.retain(|text| {
+                !base_texts.contains(text)
+                    || theirs_texts.is_some_and(|theirs_texts| theirs_texts.contains(text))
+            });
+        }
+    }
+
+    merged
+}
+
+// The unreleased section with the given entries. Without entries, it is only kept when asked,
+// typically because the changelog already had one or has no release.
+pub(crate) fn unreleased_section(
+    entries: BTreeMap<ChangeSetKind, Vec<String>>,
+    keep_empty: bool,
+) -> Option<Unreleased> {
+    if entries.values().all(Vec::is_empty) && !keep_empty {
+        None
+    } else {
+        Some(Unreleased::new(
+            UnreleasedHeading::new(0..0),
+            to_changes(entries),
+        ))
+    }
+}
+
+// The reference definitions follow the releases, except for the unreleased one which follows
+// the unreleased section.
+pub(crate) fn reference_definitions<'source>(
+    side: &Changelog<'source>,
+    has_unreleased: bool,
+) -> Vec<ReferenceDefinition<'source>> {
+    let mut reference_definitions: Vec<_> = side
+        .reference_definitions()
+        .iter()
+        .filter(|reference_definition| !reference_definition.is_unreleased())
+        .cloned()
+        .collect();
+    if !has_unreleased {
+        return reference_definitions;
+    }
+    if let Some(unreleased) = side
+        .reference_definitions()
+        .iter()
+        .find(|reference_definition| reference_definition.is_unreleased())
+    {
+        reference_definitions.insert(0, unreleased.clone());
+        return reference_definitions;
+    }
+    // The side we kept removed the unreleased section, but the other one added entries to it.
+    let linter = reference_definitions.last().and_then(RefDefLinter::try_new);
+    if let Some(linter) = linter {
+        let dest = match side.releases().first() {
+            Some(latest) => linter.diff_destination(&latest.version().to_string(), "Unreleased"),
+            None => linter.release_destination("Unreleased"),
+        };
+        reference_definitions.insert(
+            0,
+            ReferenceDefinition::new("Unreleased".to_string(), CowStr::from(dest), 0..0),
+        );
+    }
+    reference_definitions
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeConflict {
+    DivergentIntro,
+    DivergentReleases,
+}
+
+impl Display for MergeConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeConflict::DivergentIntro => {
+                write!(f, "the introduction was changed differently on both sides")
+            }
+            MergeConflict::DivergentReleases => {
+                write!(
+                    f,
+                    "the released sections were changed differently on both sides"
+                )
+            }
+        }
+    }
+}
+
+impl Error for MergeConflict {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ChangelogDocument;
+
+    const BASE: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Some `new` bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    fn merge_sources(base: &str, ours: &str, theirs: &str) -> Result<String, MergeConflict> {
+        let base = Changelog::parse(base).unwrap();
+        let ours = Changelog::parse(ours).unwrap();
+        let theirs = Changelog::parse(theirs).unwrap();
+        merge(&base, &ours, &theirs)
+    }
+
+    mod merge {
+        use super::*;
+
+        #[test]
+        fn should_union_unreleased_entries() {
+            let ours = BASE.replace(
+                "- Some `new` bull.\n",
+                "- Some `new` bull.\n- Our bull.\n\n### Fixed\n\n- Our fix.\n",
+            );
+            let theirs = BASE.replace(
+                "- Some `new` bull.\n",
+                "- Some `new` bull.\n- Their bull.\n\n### Changed\n\n- Their change.\n",
+            );
+            assert_eq!(
+                merge_sources(BASE, &ours, &theirs).unwrap(),
+                BASE.replace(
+                    "- Some `new` bull.\n",
+                    "- Some `new` bull.\n- Our bull.\n- Their bull.\n\n### Changed\n\n- Their change.\n\n### Fixed\n\n- Our fix.\n",
+                )
+            );
+        }
+
+        #[test]
+        fn should_drop_entries_removed_on_either_side() {
+            let ours = BASE.replace("- Some `new` bull.\n", "- Our bull.\n");
+            let theirs = BASE.replace("- Some `new` bull.\n", "- Their bull.\n");
+            assert_eq!(
+                merge_sources(BASE, &ours, &theirs).unwrap(),
+                BASE.replace("- Some `new` bull.\n", "- Our bull.\n- Their bull.\n")
+            );
+        }
+
+        #[test]
+        fn should_keep_release_cut_on_one_side() {
+            let ours = r"# Changelog
+
+Some intro.
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- Some `new` bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+            let theirs = BASE.replace(
+                "- Some `new` bull.\n",
+                "- Some `new` bull.\n- Their bull.\n",
+            );
+            assert_eq!(
+                merge_sources(BASE, ours, &theirs).unwrap(),
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Their bull.
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- Some `new` bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+"
+            );
+        }
+
+        #[test]
+        fn should_conflict_on_divergent_releases() {
+            let ours = BASE.replace("2026-01-01", "2026-01-02");
+            let theirs = BASE.replace("2026-01-01", "2026-01-03");
+            assert_eq!(
+                merge_sources(BASE, &ours, &theirs),
+                Err(MergeConflict::DivergentReleases)
+            );
+        }
+
+        #[test]
+        fn should_keep_empty_unreleased_section_of_new_changelog() {
+            let base = ChangelogDocument::new("https://github.com/owner/repo".to_string())
+                .to_markdown()
+                .unwrap();
+            assert_eq!(merge_sources(&base, &base, &base), Ok(base.clone()));
+
+            let ours = base.replace(
+                "## [Unreleased]\n",
+                "## [Unreleased]\n\n### Added\n\n- Our bull.\n",
+            );
+            let theirs = base.replace(
+                "## [Unreleased]\n",
+                "## [Unreleased]\n\n### Fixed\n\n- Their fix.\n",
+            );
+            let merged = merge_sources(&base, &ours, &theirs).unwrap();
+            assert!(merged.contains("### Added\n\n- Our bull.\n\n### Fixed\n\n- Their fix.\n"));
+            // Removing the entries on one side leaves the section empty, rather than gone.
+            let merged = merge_sources(&ours, &ours, &base).unwrap();
+            assert_eq!(merged, base);
+        }
+    }
+}
@@ -4,6 +4,8 @@ use changelog_ast::{AstIterator, Node, RefDefs};
 
 #[derive(Debug)]
 pub struct Ast<'source> {
+    /// The markdown source the nodes were produced from.
+    source: &'source str,
     /// The eagerly collected nodes sift through for parsing.
     nodes: VecDeque<Node<'source>>,
     /// The exhausted iterator to extract reference definitions from.
@@ -24,6 +26,10 @@ impl<'source> Ast<'source> {
         self.nodes.is_empty()
     }
 
+    pub fn source(&self) -> &'source str {
+        self.source
+    }
+
     pub fn get(&self, index: usize) -> Option<&Node<'source>> {
         self.nodes.get(index)
     }
@@ -32,20 +38,23 @@ impl<'source> Ast<'source> {
         self.iter.reference_definitions()
     }
 
-    fn new(nodes: VecDeque<Node<'source>>, iter: AstIterator<'source>) -> Self {
-        Self { nodes, iter }
-    }
-}
-
-impl<'source> From<AstIterator<'source>> for Ast<'source> {
-    fn from(mut iter: AstIterator<'source>) -> Self {
-        let nodes = iter.by_ref().collect();
-        Self::new(nodes, iter)
+    fn new(
+        source: &'source str,
+        nodes: VecDeque<Node<'source>>,
+        iter: AstIterator<'source>,
+    ) -> Self {
+        Self {
+            source,
+            nodes,
+            iter,
+        }
     }
 }
 
 impl<'source> From<&'source str> for Ast<'source> {
     fn from(value: &'source str) -> Self {
-        AstIterator::new(value).into()
+        let mut iter = AstIterator::new(value);
+        let nodes = iter.by_ref().collect();
+        Self::new(value, nodes, iter)
     }
 }
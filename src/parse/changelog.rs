@@ -1,4 +1,4 @@
-use std::{error::Error, fmt::Display};
+use std::{error::Error, fmt::Display, ops::Range};
 
 use crate::parse::{
     ast::Ast,
@@ -9,11 +9,14 @@ use crate::parse::{
 
 // TODO: implement ToOwned
 // TODO: force to have at least an unreleased or a release?
+// Not deserializable, as the ranges would point into a source that isn't part of the export.
+// Exports are imported back through their markdown instead, see `import_export`.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Changelog<'source> {
-    #[allow(dead_code)]
+    #[cfg_attr(feature = "serde", serde(skip))]
     source: &'source str,
-    #[allow(dead_code)]
     title: Title,
     /// The unreleased section of a document is optional, as it would basically become empty
     /// after each release. So, whether the user decides to have one or not, is up to them.
@@ -23,7 +26,7 @@ pub struct Changelog<'source> {
 }
 
 impl<'source> Changelog<'source> {
-    fn new(
+    pub(crate) fn new(
         source: &'source str,
         title: Title,
         unreleased: Option<Unreleased>,
@@ -78,6 +81,14 @@ impl<'source> Changelog<'source> {
         ))
     }
 
+    pub(crate) fn source(&self) -> &'source str {
+        self.source
+    }
+
+    pub(crate) fn title(&self) -> &Title {
+        &self.title
+    }
+
     pub fn unreleased(&self) -> &Option<Unreleased> {
         &self.unreleased
     }
@@ -101,6 +112,17 @@ pub enum ChangelogParseError {
     InvalidRelease(ReleaseParseError),
 }
 
+impl ChangelogParseError {
+    /// The source range at fault, if any.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match self {
+            ChangelogParseError::InvalidTitle(err) => err.range(),
+            ChangelogParseError::InvalidUnreleased(err) => err.range(),
+            ChangelogParseError::InvalidRelease(err) => err.range(),
+        }
+    }
+}
+
 impl From<TitleParseError> for ChangelogParseError {
     fn from(err: TitleParseError) -> Self {
         ChangelogParseError::InvalidTitle(err)
@@ -0,0 +1,363 @@
+use std::ops::Range;
+
+use changelog_ast::CowStr;
+
+use crate::parse::{
+    ast::Ast,
+    changelog::{Changelog, ChangelogParseError},
+    reference_definition::ReferenceDefinition,
+    releases::{Change, ChangeSet, Changes, Release, Yanked},
+};
+
+/// The replacement of a byte range of a source with some text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    range: Range<usize>,
+    text: String,
+}
+
+impl TextEdit {
+    pub fn new(range: Range<usize>, text: impl Into<String>) -> Self {
+        Self {
+            range,
+            text: text.into(),
+        }
+    }
+
+    pub fn range(&self) -> &Range<usize> {
+        &self.range
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Applies the edit to the source, returning the edited text.
+    pub fn apply(&self, source: &str) -> String {
+        let mut edited = String::with_capacity(source.len() + self.text.len());
+        edited.push_str(&source[..self.range.start]);
+        edited.push_str(&self.text);
+        edited.push_str(&source[self.range.end..]);
+        edited
+    }
+}
+
+// The region of the source touched by a sequence of edits. Before `start`, the original and
+// the edited sources are identical. The original from `old_end` on is the edited one from
+// `new_end` on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Dirty {
+    start: usize,
+    old_end: usize,
+    new_end: usize,
+}
+
+impl Dirty {
+    // Each edit is relative to the source resulting from the previous ones.
+    fn of(edits: &[TextEdit]) -> Option<Self> {
+        let mut edits = edits.iter();
+        let first = edits.next()?;
+        let mut dirty = Dirty {
+            start: first.range.start,
+            old_end: first.range.end,
+            new_end: first.range.start + first.text.len(),
+        };
+        for edit in edits {
+            let end = dirty.new_end.max(edit.range.end);
+            dirty.old_end += end - dirty.new_end;
+            dirty.start = dirty.start.min(edit.range.start);
+            dirty.new_end = end + edit.text.len() - edit.range.len();
+        }
+        Some(dirty)
+    }
+
+    fn delta(&self) -> isize {
+        self.new_end as isize - self.old_end as isize
+    }
+}
+
+impl<'source> Changelog<'source> {
+    /// Parses the source resulting from applying the edits to the source of this changelog.
+    ///
+    /// Releases are independent level 2 sections, so only those the edits touch are parsed
+    /// again. The others are reused, with their ranges shifted. Edits reaching the title,
+    /// the unreleased section or the reference definitions fall back to a full parse. The
+    /// outcome is the same as [`Changelog::parse`] on the edited source.
+    pub fn reparse<'edited>(
+        &self,
+        source: &'edited str,
+        edits: &[TextEdit],
+    ) -> Result<Changelog<'edited>, ChangelogParseError> {
+        match Dirty::of(edits) {
+            Some(dirty)
+                if self.source().len() as isize + dirty.delta() == source.len() as isize =>
+            {
+                match self.reparse_releases(source, dirty) {
+                    Some(changelog) => Ok(changelog),
+                    None => Changelog::parse(source),
+                }
+            }
+            _ => Changelog::parse(source),
+        }
+    }
+
+    // None when the edits aren't confined to the releases.
+    fn reparse_releases<'edited>(
+        &self,
+        source: &'edited str,
+        dirty: Dirty,
+    ) -> Option<Changelog<'edited>> {
+        let releases = self.releases();
+        let starts: Vec<usize> = releases
+            .iter()
+            .map(|release| release.heading().start)
+            .collect();
+        // Reference definitions are expected after the releases.
+        let tail = self
+            .reference_definitions()
+            .iter()
+            .map(|reference_definition| reference_definition.range().start)
+            .min()
+            .unwrap_or(self.source().len());
+        if dirty.start <= *starts.first()? || dirty.old_end >= tail {
+            return None;
+        }
+
+        // An edit right at a heading may just as well extend the previous release.
+        let first = starts.iter().rposition(|start| *start < dirty.start)?;
+        let last = starts.iter().rposition(|start| *start <= dirty.old_end)?;
+        let start = starts[first];
+        let old_end = starts.get(last + 1).copied().unwrap_or(tail);
+        let delta = dirty.delta();
+        let end = old_end.checked_add_signed(delta)?;
+        let tail = tail.checked_add_signed(delta)?;
+
+        // The reference definitions are needed to resolve the links of the release headings.
+        // They can't follow the region right away, they would be lazy continuation lines.
+        let region = &source[start..end];
+        if !region.ends_with("\n\n") && !region.ends_with("\n\r\n") {
+            return None;
+        }
+        let region = format!("{}{}", region, &source[tail..]);
+        let mut ast = Ast::from(region.as_str());
+        let mut reparsed = vec![];
+        loop {
+            // A failed parse may have consumed nodes already, so where it began tells whether
+            // something else than a release is left in the region.
+            let front = ast.front().map(|node| node.range().start);
+            match Release::parse(&mut ast) {
+                Ok(release) => reparsed.push(shift_release(&release, start as isize)),
+                Err(_) if front.is_some_and(|front| front < end - start) => return None,
+                Err(_) => break,
+            }
+        }
+        if reparsed.is_empty() {
+            return None;
+        }
+
+        let releases = releases[..first]
+            .iter()
+            .cloned()
+            .chain(reparsed)
+            .chain(
+                releases[last + 1..]
+                    .iter()
+                    .map(|release| shift_release(release, delta)),
+            )
+            .collect();
+        let reference_definitions = self
+            .reference_definitions()
+            .iter()
+            .map(|reference_definition| {
+                ReferenceDefinition::new(
+                    reference_definition.label().to_string(),
+                    CowStr::from(reference_definition.dest().to_string()),
+                    shift(reference_definition.range(), delta),
+                )
+            })
+            .collect();
+        Some(Changelog::new(
+            source,
+            self.title().clone(),
+            self.unreleased().clone(),
+            releases,
+            reference_definitions,
+        ))
+    }
+}
+
+fn shift(range: &Range<usize>, delta: isize) -> Range<usize> {
+    range.start.saturating_add_signed(delta)..range.end.saturating_add_signed(delta)
+}
+
+fn shift_release(release: &Release, delta: isize) -> Release {
+    let change_sets = release
+        .changes()
+        .iter()
+        .map(|change_set| {
+            let items = change_set
+                .items()
+                .iter()
+                .map(|change| Change::new(shift(change.range(), delta), change.text().to_string()))
+                .collect();
+            ChangeSet::from((change_set.kind(), shift(change_set.heading(), delta), items))
+        })
+        .collect();
+    Release::new(
+        shift(release.heading(), delta),
+        release.version().clone(),
+        *release.date(),
+        Changes::new(change_sets),
+        Yanked(release.is_yanked()),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- New bull.
+
+## [0.3.0] - 2026-03-01
+
+### Fixed
+
+- The bull.
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- More bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.3.0...HEAD
+[0.3.0]: https://github.com/owner/repo/compare/v0.2.0...v0.3.0
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    fn assert_same(reparsed: &Changelog, parsed: &Changelog) {
+        assert_eq!(reparsed.title(), parsed.title());
+        assert_eq!(reparsed.unreleased(), parsed.unreleased());
+        assert_eq!(reparsed.releases(), parsed.releases());
+        let ref_defs = |changelog: &Changelog| -> Vec<(String, String, Range<usize>)> {
+            changelog
+                .reference_definitions()
+                .iter()
+                .map(|rd| (rd.label().into(), rd.dest().into(), rd.range().clone()))
+                .collect()
+        };
+        assert_eq!(ref_defs(reparsed), ref_defs(parsed));
+    }
+
+    fn edit(find: &str, text: &str) -> TextEdit {
+        let start = SOURCE.find(find).unwrap();
+        TextEdit::new(start..start + find.len(), text)
+    }
+
+    mod reparse {
+        use super::*;
+
+        #[test]
+        fn should_match_full_parse_for_edit_within_release() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let edits = [edit("- More bull.", "- More bull.\n- Even more bull.")];
+            let source = edits[0].apply(SOURCE);
+            let reparsed = changelog
+                .reparse_releases(&source, Dirty::of(&edits).unwrap())
+                .unwrap();
+            assert_same(&reparsed, &Changelog::parse(&source).unwrap());
+        }
+
+        #[test]
+        fn should_match_full_parse_for_sequential_edits() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let first = edit("The bull.", "A");
+            let source = first.apply(SOURCE);
+            let start = source.find("More bull.").unwrap();
+            let second = TextEdit::new(start..start + 4, "Much more");
+            let source = second.apply(&source);
+            let edits = [first, second];
+            let reparsed = changelog
+                .reparse_releases(&source, Dirty::of(&edits).unwrap())
+                .unwrap();
+            assert_same(&reparsed, &Changelog::parse(&source).unwrap());
+        }
+
+        #[test]
+        fn should_match_full_parse_for_edited_heading() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let edits = [edit(
+                "## [0.2.0] - 2026-02-01",
+                "## [0.2.0] - 2026-02-11 [YANKED]",
+            )];
+            let source = edits[0].apply(SOURCE);
+            let reparsed = changelog
+                .reparse_releases(&source, Dirty::of(&edits).unwrap())
+                .unwrap();
+            assert_same(&reparsed, &Changelog::parse(&source).unwrap());
+        }
+
+        #[test]
+        fn should_fall_back_outside_releases() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            for edits in [
+                [edit("Some intro.", "Another intro.")],
+                [edit("- New bull.", "- New bull.\n- Newer bull.")],
+                [edit("releases/tag/v0.1.0", "releases/tag/v0.1.1")],
+            ] {
+                let source = edits[0].apply(SOURCE);
+                let reparsed = changelog.reparse(&source, &edits).unwrap();
+                assert_same(&reparsed, &Changelog::parse(&source).unwrap());
+            }
+        }
+
+        #[test]
+        fn should_fall_back_for_invalid_release() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let edits = [edit("### Fixed", "### Fuckulated")];
+            let source = edits[0].apply(SOURCE);
+            match (
+                changelog.reparse(&source, &edits),
+                Changelog::parse(&source),
+            ) {
+                (Ok(reparsed), Ok(parsed)) => assert_same(&reparsed, &parsed),
+                (reparsed, parsed) => assert_eq!(reparsed.err(), parsed.err()),
+            }
+        }
+
+        #[test]
+        fn should_fall_back_for_invalid_release_after_valid_one() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let first = edit("The bull.", "A");
+            let source = first.apply(SOURCE);
+            let start = source.find("- More bull.").unwrap();
+            let second = TextEdit::new(start..start, "- More bull.\n\n### Added\n\n");
+            let source = second.apply(&source);
+            let edits = [first, second];
+            assert!(
+                changelog
+                    .reparse_releases(&source, Dirty::of(&edits).unwrap())
+                    .is_none()
+            );
+            assert_eq!(
+                changelog.reparse(&source, &edits).err(),
+                Changelog::parse(&source).err()
+            );
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod ast;
 mod changelog;
+mod incremental;
 mod node_ext;
 mod reference_definition;
 mod releases;
@@ -7,5 +8,7 @@ mod title;
 
 // TODO: reduce visibility when ready to publish crate.
 pub use changelog::{Changelog, ChangelogParseError};
+pub use incremental::TextEdit;
 pub use reference_definition::*;
 pub use releases::*;
+pub(crate) use title::{Title, TitleHeading};
@@ -3,8 +3,12 @@ use std::ops::Range;
 use changelog_ast::CowStr;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ReferenceDefinition<'source> {
     label: String,
+    #[cfg_attr(feature = "serde", serde(with = "cow_str"))]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     dest: CowStr<'source>,
     range: Range<usize>,
 }
@@ -19,6 +23,11 @@ impl<'source> ReferenceDefinition<'source> {
         &self.label
     }
 
+    /// Whether this is the definition of the "Unreleased" section link.
+    pub fn is_unreleased(&self) -> bool {
+        self.label.eq_ignore_ascii_case("unreleased")
+    }
+
     pub fn dest(&self) -> &str {
         &self.dest
     }
@@ -27,3 +36,20 @@ impl<'source> ReferenceDefinition<'source> {
         &self.range
     }
 }
+
+/// [CowStr] doesn't implement serde's traits, so we go through [str] and [String] instead.
+#[cfg(feature = "serde")]
+mod cow_str {
+    use changelog_ast::CowStr;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &CowStr<'_>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value)
+    }
+
+    pub fn deserialize<'de, 'source, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<CowStr<'source>, D::Error> {
+        String::deserialize(deserializer).map(CowStr::from)
+    }
+}
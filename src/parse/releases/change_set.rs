@@ -1,7 +1,7 @@
 use std::ops::Range;
 
 pub use change::*;
-use change_set_kind::*;
+pub use change_set_kind::*;
 
 use changelog_ast::{HeadingLevel, Node};
 
@@ -12,30 +12,48 @@ mod change {
 
     use changelog_ast::{Item, Node};
 
+    /// A single entry of a change set, i.e. one item of its list.
     #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
     pub struct Change {
         pub(crate) range: Range<usize>,
+        /// The markdown content of the item, without the list marker.
+        text: String,
     }
 
     impl Change {
-        pub fn new(range: Range<usize>) -> Self {
-            Self { range }
+        pub fn new(range: Range<usize>, text: String) -> Self {
+            Self { range, text }
+        }
+
+        pub fn range(&self) -> &Range<usize> {
+            &self.range
+        }
+
+        pub fn text(&self) -> &str {
+            &self.text
         }
     }
 
-    impl From<&Item<'_>> for Change {
-        fn from(value: &Item<'_>) -> Self {
-            Self::new(value.range.clone())
+    impl From<(&Item<'_>, &str)> for Change {
+        fn from((item, source): (&Item<'_>, &str)) -> Self {
+            // The item's range includes the list marker, its children's don't.
+            let text = match (item.children.first(), item.children.last()) {
+                (Some(first), Some(last)) => source[first.range().start..last.range().end].trim(),
+                _ => "",
+            };
+            Self::new(item.range.clone(), text.replace("\r\n", "\n"))
         }
     }
 
-    impl TryFrom<&Node<'_>> for Change {
+    impl TryFrom<(&Node<'_>, &str)> for Change {
         type Error = Range<usize>;
 
-        fn try_from(value: &Node<'_>) -> Result<Self, Self::Error> {
-            match value {
-                Node::Item(item) => Ok(item.into()),
-                _ => Err(value.range().clone()),
+        fn try_from((node, source): (&Node<'_>, &str)) -> Result<Self, Self::Error> {
+            match node {
+                Node::Item(item) => Ok((item, source).into()),
+                _ => Err(node.range().clone()),
             }
         }
     }
@@ -52,29 +70,52 @@ mod change {
             #[test]
             fn should_error_for_text() {
                 let node = Node::Text(Text::new(0..12, CowStr::from("Please bro stfu")));
-                assert_eq!(Change::try_from(&node), Err(0..12));
+                assert_eq!(Change::try_from((&node, "Please bro stfu")), Err(0..12));
             }
 
             #[test]
             fn should_succeed_for_item() {
+                let source = "- now you really gots to stfu";
                 let node = Node::Item(Item::new(
-                    0..12,
+                    0..29,
                     vec![Node::Text(Text::new(
-                        1..12,
-                        CowStr::from("now you really gots to stfu gotta gotta gots to"),
+                        2..29,
+                        CowStr::from("now you really gots to stfu"),
                     ))],
                 ));
-                assert_eq!(Change::try_from(&node), Ok(Change::new(0..12)));
+                assert_eq!(
+                    Change::try_from((&node, source)),
+                    Ok(Change::new(0..29, "now you really gots to stfu".into()))
+                );
+            }
+
+            #[test]
+            fn should_succeed_for_empty_item() {
+                let node = Node::Item(Item::new(0..1, vec![]));
+                assert_eq!(
+                    Change::try_from((&node, "-")),
+                    Ok(Change::new(0..1, "".into()))
+                );
             }
         }
     }
 }
 
 mod change_set_kind {
+    use std::fmt::Display;
+
     use changelog_ast::{Node, Text};
 
     /// An enum regrouping the allowed kinds of change sets.
-    #[derive(Debug, Clone, PartialEq, Eq)]
+    ///
+    /// The variants are declared in the order they are expected to appear in a release.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(
+        feature = "serde",
+        derive(serde::Serialize, serde::Deserialize),
+        serde(rename_all = "lowercase")
+    )]
+    #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
     pub enum ChangeSetKind {
         /// Added items in a change set.
         Added,
@@ -106,6 +147,20 @@ mod change_set_kind {
         }
     }
 
+    impl Display for ChangeSetKind {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let text = match self {
+                ChangeSetKind::Added => "Added",
+                ChangeSetKind::Changed => "Changed",
+                ChangeSetKind::Deprecated => "Deprecated",
+                ChangeSetKind::Fixed => "Fixed",
+                ChangeSetKind::Removed => "Removed",
+                ChangeSetKind::Security => "Security",
+            };
+            write!(f, "{}", text)
+        }
+    }
+
     impl TryFrom<&Text<'_>> for ChangeSetKind {
         type Error = ();
 
@@ -205,7 +260,26 @@ pub enum ChangeSetParseError {
     InvalidChangesList(Range<usize>),
 }
 
+impl ChangeSetParseError {
+    /// The source range at fault, if any.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match self {
+            ChangeSetParseError::Empty => None,
+            ChangeSetParseError::InvalidHeading(range)
+            | ChangeSetParseError::InvalidHeader(range)
+            | ChangeSetParseError::InvalidItem(range)
+            | ChangeSetParseError::InvalidChangesList(range) => Some(range.clone()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind", rename_all = "lowercase")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum ChangeSet {
     Added(Added),
     Changed(Changed),
@@ -227,6 +301,40 @@ impl ChangeSet {
         }
     }
 
+    /// The source range of the change set heading.
+    pub fn heading(&self) -> &Range<usize> {
+        match self {
+            ChangeSet::Added(inner) => inner.heading(),
+            ChangeSet::Changed(inner) => inner.heading(),
+            ChangeSet::Deprecated(inner) => inner.heading(),
+            ChangeSet::Fixed(inner) => inner.heading(),
+            ChangeSet::Removed(inner) => inner.heading(),
+            ChangeSet::Security(inner) => inner.heading(),
+        }
+    }
+
+    pub fn kind(&self) -> ChangeSetKind {
+        match self {
+            ChangeSet::Added(_) => ChangeSetKind::Added,
+            ChangeSet::Changed(_) => ChangeSetKind::Changed,
+            ChangeSet::Deprecated(_) => ChangeSetKind::Deprecated,
+            ChangeSet::Fixed(_) => ChangeSetKind::Fixed,
+            ChangeSet::Removed(_) => ChangeSetKind::Removed,
+            ChangeSet::Security(_) => ChangeSetKind::Security,
+        }
+    }
+
+    pub fn items(&self) -> &[Change] {
+        match self {
+            ChangeSet::Added(inner) => inner.items(),
+            ChangeSet::Changed(inner) => inner.items(),
+            ChangeSet::Deprecated(inner) => inner.items(),
+            ChangeSet::Fixed(inner) => inner.items(),
+            ChangeSet::Removed(inner) => inner.items(),
+            ChangeSet::Security(inner) => inner.items(),
+        }
+    }
+
     pub fn is_added(&self) -> bool {
         matches!(self, ChangeSet::Added(_))
     }
@@ -287,7 +395,7 @@ impl ChangeSet {
 
         let items = second
             .children()
-            .map(Change::try_from)
+            .map(|node| Change::try_from((node, ast.source())))
             .collect::<Result<Vec<_>, _>>()
             .map_err(ChangeSetParseError::InvalidItem)?;
 
@@ -332,7 +440,10 @@ mod test {
             ChangeSet::parse(&mut ast),
             Ok(ChangeSet::Added(Added::new(
                 0..10,
-                vec![Change::new(11..25), Change::new(25..36)]
+                vec![
+                    Change::new(11..25, "Some sheeet".into()),
+                    Change::new(25..36, "Big sheet".into())
+                ]
             )))
         );
         assert!(ast.is_empty());
@@ -345,7 +456,10 @@ mod test {
             ChangeSet::parse(&mut ast),
             Ok(ChangeSet::Changed(Changed::new(
                 0..12,
-                vec![Change::new(13..27), Change::new(27..38)]
+                vec![
+                    Change::new(13..27, "Some sheeet".into()),
+                    Change::new(27..38, "Big sheet".into())
+                ]
             )))
         );
         assert!(ast.is_empty());
@@ -358,7 +472,10 @@ mod test {
             ChangeSet::parse(&mut ast),
             Ok(ChangeSet::Deprecated(Deprecated::new(
                 0..15,
-                vec![Change::new(16..30), Change::new(30..41)]
+                vec![
+                    Change::new(16..30, "Some sheeet".into()),
+                    Change::new(30..41, "Big sheet".into())
+                ]
             )))
         );
         assert!(ast.is_empty());
@@ -371,7 +488,10 @@ mod test {
             ChangeSet::parse(&mut ast),
             Ok(ChangeSet::Fixed(Fixed::new(
                 0..10,
-                vec![Change::new(11..25), Change::new(25..36)]
+                vec![
+                    Change::new(11..25, "Some sheeet".into()),
+                    Change::new(25..36, "Big sheet".into())
+                ]
             )))
         );
         assert!(ast.is_empty());
@@ -384,7 +504,10 @@ mod test {
             ChangeSet::parse(&mut ast),
             Ok(ChangeSet::Removed(Removed::new(
                 0..12,
-                vec![Change::new(13..27), Change::new(27..38)]
+                vec![
+                    Change::new(13..27, "Some sheeet".into()),
+                    Change::new(27..38, "Big sheet".into())
+                ]
             )))
         );
         assert!(ast.is_empty());
@@ -397,7 +520,10 @@ mod test {
             ChangeSet::parse(&mut ast),
             Ok(ChangeSet::Security(Security::new(
                 0..13,
-                vec![Change::new(14..28), Change::new(28..39)]
+                vec![
+                    Change::new(14..28, "Some sheeet".into()),
+                    Change::new(28..39, "Big sheet".into())
+                ]
             )))
         );
         assert!(ast.is_empty());
@@ -407,6 +533,8 @@ mod test {
 macro_rules! ChangeSetVariant {
     ($name:ident) => {
         #[derive(Debug, Default, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
         pub struct $name {
             heading: Range<usize>,
             items: Vec<Change>,
@@ -417,6 +545,11 @@ macro_rules! ChangeSetVariant {
                 Self { heading, items }
             }
 
+            /// The source range of the change set heading.
+            pub fn heading(&self) -> &Range<usize> {
+                &self.heading
+            }
+
             /// Returns the range covering the whole change set.
             pub fn range(&self) -> Range<usize> {
                 let start = self.heading.start;
@@ -427,6 +560,10 @@ macro_rules! ChangeSetVariant {
                     .unwrap_or(self.heading.end);
                 start..end
             }
+
+            pub fn items(&self) -> &[Change] {
+                &self.items
+            }
         }
 
         impl From<$name> for ChangeSet {
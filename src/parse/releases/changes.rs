@@ -6,6 +6,12 @@ use crate::parse::{
 };
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Changes {
     change_sets: Vec<ChangeSet>,
 }
@@ -42,7 +48,7 @@ impl Changes {
         }
     }
 
-    fn new(change_sets: Vec<ChangeSet>) -> Self {
+    pub(crate) fn new(change_sets: Vec<ChangeSet>) -> Self {
         Self { change_sets }
     }
 }
@@ -62,6 +68,16 @@ pub enum ChangesParseError {
     },
 }
 
+impl ChangesParseError {
+    /// The source range at fault, if any.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match self {
+            ChangesParseError::InvalidChangeSet(err) => err.range(),
+            ChangesParseError::DuplicateChangeSet { second, .. } => Some(second.clone()),
+        }
+    }
+}
+
 impl From<ChangeSetParseError> for ChangesParseError {
     fn from(value: ChangeSetParseError) -> Self {
         Self::InvalidChangeSet(value)
@@ -114,12 +130,35 @@ mod test {
         assert_eq!(
             result,
             Ok(Changes::new(vec![
-                Added::new(0..10, vec![Change::new(10..37)]).into(),
-                Changed::new(37..49, vec![Change::new(49..71)]).into(),
-                Deprecated::new(71..86, vec![Change::new(86..108)]).into(),
-                Fixed::new(108..118, vec![Change::new(118..152)]).into(),
-                Removed::new(152..164, vec![Change::new(164..187)]).into(),
-                Security::new(187..200, vec![Change::new(200..211)]).into(),
+                Added::new(
+                    0..10,
+                    vec![Change::new(10..37, "the stuff you don't want".into())]
+                )
+                .into(),
+                Changed::new(
+                    37..49,
+                    vec![Change::new(49..71, "the stuff you liked".into())]
+                )
+                .into(),
+                Deprecated::new(
+                    71..86,
+                    vec![Change::new(86..108, "the brand new stuff".into())]
+                )
+                .into(),
+                Fixed::new(
+                    108..118,
+                    vec![Change::new(
+                        118..152,
+                        "the stuff you didn't care about".into()
+                    )]
+                )
+                .into(),
+                Removed::new(
+                    152..164,
+                    vec![Change::new(164..187, "the stuff you needed".into())]
+                )
+                .into(),
+                Security::new(187..200, vec![Change::new(200..211, "ooopsies".into())]).into(),
             ]))
         );
     }
@@ -9,9 +9,17 @@ use crate::parse::{
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Yanked(pub bool);
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub(crate) struct Yanked(pub bool);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Release {
     heading: Range<usize>,
     version: Version,
@@ -21,6 +29,11 @@ pub struct Release {
 }
 
 impl Release {
+    /// The source range of the release heading.
+    pub fn heading(&self) -> &Range<usize> {
+        &self.heading
+    }
+
     pub fn changes(&self) -> &Changes {
         &self.changes
     }
@@ -44,7 +57,7 @@ impl Release {
         Ok(Release::new(heading, version, date, changes, yanked))
     }
 
-    fn new(
+    pub(crate) fn new(
         heading: Range<usize>,
         version: Version,
         date: NaiveDate,
@@ -67,6 +80,16 @@ pub enum ReleaseParseError {
     InvalidChanges(ChangesParseError),
 }
 
+impl ReleaseParseError {
+    /// The source range at fault, if any.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match self {
+            ReleaseParseError::InvalidHeading(err) => err.range(),
+            ReleaseParseError::InvalidChanges(err) => err.range(),
+        }
+    }
+}
+
 impl From<heading::ParseError> for ReleaseParseError {
     fn from(value: heading::ParseError) -> Self {
         Self::InvalidHeading(value)
@@ -116,7 +139,6 @@ mod heading {
     fn parse_heading_children(
         nodes: &[Node<'_>],
     ) -> Result<(Version, NaiveDate, Yanked), ParseError> {
-        dbg!(nodes);
         // When the version is properly linked, the heading should have 2 children: a link and a text
         // event with the date following.
         // Optionally, the heading can also end with the [YANKED] annotation. In which case, there
@@ -324,6 +346,19 @@ mod heading {
         InvalidVersion(Range<usize>),
     }
 
+    impl ParseError {
+        pub fn range(&self) -> Option<Range<usize>> {
+            match self {
+                ParseError::Empty => None,
+                ParseError::BrokenLink(range)
+                | ParseError::InvalidDate(range)
+                | ParseError::InvalidHeading(range)
+                | ParseError::InvalidText(range)
+                | ParseError::InvalidVersion(range) => Some(range.clone()),
+            }
+        }
+    }
+
     #[cfg(test)]
     mod test {
         use super::*;
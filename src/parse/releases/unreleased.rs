@@ -1,5 +1,10 @@
+use std::ops::Range;
+
+use changelog_ast::HeadingLevel;
+
 use crate::parse::{
     ast::Ast,
+    node_ext::NodeExt,
     releases::{Changes, ChangesParseError},
 };
 pub use heading::*;
@@ -9,6 +14,8 @@ pub use heading::*;
 /// Unlike the regular releases of a changelog, the unreleased
 /// section does not have any version attributed.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Unreleased {
     /// The unreleased section heading.
     heading: UnreleasedHeading,
@@ -17,6 +24,11 @@ pub struct Unreleased {
 }
 
 impl Unreleased {
+    /// The source range of the unreleased heading.
+    pub fn heading(&self) -> &Range<usize> {
+        &self.heading.range
+    }
+
     pub fn changes(&self) -> &Changes {
         &self.changes
     }
@@ -27,7 +39,12 @@ impl Unreleased {
 
     pub(crate) fn parse(ast: &mut Ast) -> Result<Self, UnreleasedParseError> {
         let heading = UnreleasedHeading::parse(ast)?;
-        let changes = Changes::parse(ast)?;
+        // Unlike releases, the section may be empty, like in a brand new changelog.
+        let changes = match ast.front() {
+            None => Changes::default(),
+            Some(node) if node.is_heading_of_level(HeadingLevel::H2) => Changes::default(),
+            Some(_) => Changes::parse(ast)?,
+        };
         Ok(Self::new(heading, changes))
     }
 }
@@ -93,6 +110,24 @@ mod test {
             )
         }
 
+        #[test]
+        fn should_succeed_for_empty_unreleased_block() {
+            for source in [
+                "## [Unreleased]\n\n[Unreleased]: www.porque.pork.cunt",
+                "## [Unreleased]\n\n## [0.1.0] - 2026-01-01\n\n### Added\n- stuff\n\n[Unreleased]: www.porque.pork.cunt",
+            ] {
+                let mut ast = Ast::from(source);
+                let result = Unreleased::parse(&mut ast);
+                assert_eq!(
+                    result,
+                    Ok(Unreleased::new(
+                        UnreleasedHeading::new(0..16),
+                        Changes::default()
+                    ))
+                );
+            }
+        }
+
         #[test]
         fn should_succeed_for_valid_unreleased_block() {
             let mut ast = Ast::from(
@@ -103,7 +138,10 @@ mod test {
                 result,
                 Ok(Unreleased::new(
                     UnreleasedHeading::new(0..16),
-                    Changes::from(Changed::new(17..29, vec![Change::new(29..38)]))
+                    Changes::from(Changed::new(
+                        17..29,
+                        vec![Change::new(29..38, "stuff".into())]
+                    ))
                 ))
             );
         }
@@ -118,6 +156,8 @@ mod heading {
     use crate::parse::{ast::Ast, node_ext::NodeExt};
 
     #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
     pub struct UnreleasedHeading {
         pub(crate) range: Range<usize>,
     }
@@ -5,14 +5,26 @@ pub use heading::*;
 use crate::parse::ast::Ast;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Title {
     heading: TitleHeading,
     text: Range<usize>,
+    /// The markdown content found under the heading, verbatim.
+    intro: String,
 }
 
 impl Title {
-    pub(crate) fn new(heading: TitleHeading, text: Range<usize>) -> Self {
-        Self { heading, text }
+    pub(crate) fn new(heading: TitleHeading, text: Range<usize>, intro: String) -> Self {
+        Self {
+            heading,
+            text,
+            intro,
+        }
+    }
+
+    pub fn intro(&self) -> &str {
+        &self.intro
     }
 
     pub(crate) fn parse(ast: &mut Ast) -> Result<Self, TitleParseError> {
@@ -36,7 +48,11 @@ impl Title {
         if text_start == usize::MAX {
             Err(TitleParseError::MissingContent)
         } else {
-            Ok(Self::new(heading, text_start..text_end))
+            // The ranges point at the source as is, but the intro is kept with LF line endings.
+            let intro = ast.source()[text_start..text_end]
+                .trim()
+                .replace("\r\n", "\n");
+            Ok(Self::new(heading, text_start..text_end, intro))
         }
     }
 }
@@ -47,6 +63,19 @@ pub enum TitleParseError {
     MissingContent,
 }
 
+impl TitleParseError {
+    /// The source range at fault, if any.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match self {
+            TitleParseError::InvalidHeading(TitleHeadingParseError::InvalidNode(range))
+            | TitleParseError::InvalidHeading(TitleHeadingParseError::InvalidText(range)) => {
+                Some(range.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
 impl Display for TitleParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -94,9 +123,30 @@ mod test {
         fn should_work_with_valid_title() {
             let mut ast = Ast::from("# Changelog\n\nIpsum lorem stfu etc...\n\nContinued bro?");
             let result = Title::parse(&mut ast);
-            assert_eq!(result, Ok(Title::new(TitleHeading::new(0..12), 13..52)));
+            assert_eq!(
+                result,
+                Ok(Title::new(
+                    TitleHeading::new(0..12),
+                    13..52,
+                    "Ipsum lorem stfu etc...\n\nContinued bro?".into()
+                ))
+            );
             assert!(ast.is_empty());
         }
+
+        #[test]
+        fn should_keep_crlf_ranges_and_lf_intro() {
+            let mut ast = Ast::from("# Changelog\r\n\r\nIpsum lorem\r\nstfu etc...\r\n");
+            let result = Title::parse(&mut ast);
+            assert_eq!(
+                result,
+                Ok(Title::new(
+                    TitleHeading::new(0..13),
+                    15..41,
+                    "Ipsum lorem\nstfu etc...".into()
+                ))
+            );
+        }
     }
 }
 
@@ -109,6 +159,8 @@ mod heading {
 
     // This is guaranteed to be a heading of level 1.
     #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
     pub struct TitleHeading {
         range: Range<usize>,
     }
@@ -131,9 +183,12 @@ mod heading {
                 Node::Heading(heading) => {
                     let children = &heading.children;
                     if children.len() != 1 || !children[0].is_text_equals("Changelog") {
-                        return Err(TitleHeadingParseError::InvalidText(
-                            children[0].range().start..children[children.len() - 1].range().end,
-                        ));
+                        // A heading without text, like `# `, is at fault as a whole.
+                        let range = match (children.first(), children.last()) {
+                            (Some(first), Some(last)) => first.range().start..last.range().end,
+                            _ => first.range().clone(),
+                        };
+                        return Err(TitleHeadingParseError::InvalidText(range));
                     }
 
                     let range = first.range().clone();
@@ -187,6 +242,11 @@ mod heading {
                 failure!("# Cuntlog", TitleHeadingParseError::InvalidText(2..9));
             }
 
+            #[test]
+            fn should_error_with_empty_heading() {
+                failure!("# ", TitleHeadingParseError::InvalidText(0..2));
+            }
+
             #[test]
             fn should_work_with_valid_title_heading() {
                 let mut ast = Ast::from("# Changelog");
@@ -0,0 +1,177 @@
+use std::{error::Error, fmt::Display};
+
+use chrono::NaiveDate;
+use semver::Version;
+
+use crate::{
+    Changelog, Changes, Release, Unreleased, UnreleasedHeading, Yanked, import::to_changes,
+    merge::entries,
+};
+
+impl<'source> Changelog<'source> {
+    /// Renders the changelog with its unreleased changes released as the version, on the date.
+    ///
+    /// The unreleased section is kept, empty, and the reference definitions are regenerated
+    /// to link the new release, following the repository and tag scheme of the oldest one.
+    pub fn release(&self, version: Version, date: NaiveDate) -> Result<String, ReleaseError> {
+        let changes = self
+            .unreleased()
+            .as_ref()
+            .map(|unreleased| entries(unreleased.changes()))
+            .filter(|entries| entries.values().any(|entries| !entries.is_empty()))
+            .ok_or(ReleaseError::NothingToRelease)?;
+        if let Some(latest) = self.releases().first()
+            && version <= *latest.version()
+        {
+            return Err(ReleaseError::NotNewer(version, latest.version().clone()));
+        }
+
+        let release = Release::new(0..0, version, date, to_changes(changes), Yanked(false));
+        let releases = std::iter::once(release)
+            .chain(self.releases().iter().cloned())
+            .collect();
+        let unreleased = Unreleased::new(UnreleasedHeading::new(0..0), Changes::default());
+        let released = Changelog::new(
+            "",
+            self.title().clone(),
+            Some(unreleased),
+            releases,
+            self.reference_definitions().to_vec(),
+        );
+        let reference_definitions = released
+            .fixed_reference_definitions(true)
+            .ok_or(ReleaseError::UnknownRepository)?;
+        Ok(Changelog::new(
+            "",
+            released.title().clone(),
+            released.unreleased().clone(),
+            released.releases().to_vec(),
+            reference_definitions,
+        )
+        .to_markdown())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReleaseError {
+    NothingToRelease,
+    /// The version to release, and the latest one.
+    NotNewer(Version, Version),
+    /// The repository can't be told from the reference definitions, to link the release.
+    UnknownRepository,
+}
+
+impl Display for ReleaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReleaseError::NothingToRelease => write!(f, "expected unreleased changes to release"),
+            ReleaseError::NotNewer(version, latest) => write!(
+                f,
+                "expected version {} to come after the latest release {}",
+                version, latest
+            ),
+            ReleaseError::UnknownRepository => write!(
+                f,
+                "unable to tell the repository from the reference definitions"
+            ),
+        }
+    }
+}
+
+impl Error for ReleaseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Fixed
+
+- The bull.
+
+### Added
+
+- New bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    mod release {
+        use super::*;
+
+        #[test]
+        fn should_release_unreleased_changes() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let date = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+            let released = changelog.release(Version::new(0, 2, 0), date).unwrap();
+            assert_eq!(
+                released,
+                r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- New bull.
+
+### Fixed
+
+- The bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+"
+            );
+            assert_eq!(Changelog::parse(&released).unwrap().lint(), Ok(()));
+        }
+
+        #[test]
+        fn should_error_without_unreleased_changes() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let date = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+            let released = changelog.release(Version::new(0, 2, 0), date).unwrap();
+            assert_eq!(
+                Changelog::parse(&released)
+                    .unwrap()
+                    .release(Version::new(0, 3, 0), date),
+                Err(ReleaseError::NothingToRelease)
+            );
+        }
+
+        #[test]
+        fn should_error_for_older_version() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let date = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+            assert_eq!(
+                changelog.release(Version::new(0, 1, 0), date),
+                Err(ReleaseError::NotNewer(
+                    Version::new(0, 1, 0),
+                    Version::new(0, 1, 0)
+                ))
+            );
+        }
+    }
+}
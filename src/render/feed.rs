@@ -0,0 +1,416 @@
+use std::fmt::Write;
+
+use chrono::{NaiveDate, Utc};
+
+use super::escape;
+use crate::{Changelog, Release, release_anchor};
+
+/// The syndication formats a changelog can be published as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Atom,
+    Rss,
+}
+
+impl TryFrom<&str> for FeedFormat {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "atom" => Ok(Self::Atom),
+            "rss" => Ok(Self::Rss),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The feed level information that cannot be inferred from the changelog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedMetadata {
+    title: String,
+    base_url: String,
+    description: Option<String>,
+    author: Option<String>,
+}
+
+impl FeedMetadata {
+    /// The base URL is where the changelog is published. It is used as the feed identifier,
+    /// and to link to releases that don't have a reference definition.
+    pub fn new(title: String, base_url: String) -> Self {
+        Self {
+            title,
+            base_url,
+            description: None,
+            author: None,
+        }
+    }
+
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Atom feeds name the author, falling back on the title. RSS items only credit it when
+    /// it is an email address, like `team@example.com (Product Team)`.
+    pub fn with_author(mut self, author: String) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// The stable link to the section of a release on the published changelog.
+    fn release_url(&self, release: &Release) -> String {
+        format!(
+            "{}#{}",
+            self.base_url.trim_end_matches('#'),
+            release_anchor(release.version())
+        )
+    }
+}
+
+impl<'source> Changelog<'source> {
+    /// Renders the releases of the changelog as a feed, most recent first.
+    ///
+    /// Every release becomes an entry dated from its release date. The unreleased section
+    /// is left out, since it has no date and its content is still subject to change.
+    pub fn to_feed(&self, format: FeedFormat, metadata: &FeedMetadata) -> String {
+        match format {
+            FeedFormat::Atom => self.to_atom(metadata),
+            FeedFormat::Rss => self.to_rss(metadata),
+        }
+    }
+
+    fn to_atom(&self, metadata: &FeedMetadata) -> String {
+        let mut output = String::new();
+        writeln!(output, "<?xml version=\"1.0\" encoding=\"utf-8\"?>").unwrap();
+        writeln!(output, "<feed xmlns=\"http://www.w3.org/2005/Atom\">").unwrap();
+        writeln!(output, "  <title>{}</title>", escape(&metadata.title)).unwrap();
+        if let Some(description) = &metadata.description {
+            writeln!(output, "  <subtitle>{}</subtitle>", escape(description)).unwrap();
+        }
+        writeln!(output, "  <id>{}</id>", escape(&metadata.base_url)).unwrap();
+        writeln!(output, "  <link href=\"{}\"/>", escape(&metadata.base_url)).unwrap();
+        // Atom requires both the date and the author of the feed, unlike RSS.
+        // Without releases, the feed is as fresh as the moment it is rendered.
+        let updated = self
+            .releases()
+            .first()
+            .map(|release| *release.date())
+            .unwrap_or_else(|| Utc::now().date_naive());
+        writeln!(output, "  <updated>{}</updated>", rfc3339(&updated)).unwrap();
+        writeln!(
+            output,
+            "  <author>\n    <name>{}</name>\n  </author>",
+            escape(metadata.author.as_ref().unwrap_or(&metadata.title))
+        )
+        .unwrap();
+        for release in self.releases() {
+            writeln!(output, "  <entry>").unwrap();
+            writeln!(
+                output,
+                "    <title>{}</title>",
+                escape(&entry_title(release))
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "    <id>{}</id>",
+                escape(&metadata.release_url(release))
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "    <link href=\"{}\"/>",
+                escape(&self.entry_link(metadata, release))
+            )
+            .unwrap();
+            writeln!(output, "    <updated>{}</updated>", rfc3339(release.date())).unwrap();
+            writeln!(
+                output,
+                "    <content type=\"html\">{}</content>",
+                escape(&self.entry_content(release))
+            )
+            .unwrap();
+            writeln!(output, "  </entry>").unwrap();
+        }
+        writeln!(output, "</feed>").unwrap();
+        output
+    }
+
+    fn to_rss(&self, metadata: &FeedMetadata) -> String {
+        let mut output = String::new();
+        writeln!(output, "<?xml version=\"1.0\" encoding=\"utf-8\"?>").unwrap();
+        writeln!(output, "<rss version=\"2.0\">").unwrap();
+        writeln!(output, "  <channel>").unwrap();
+        writeln!(output, "    <title>{}</title>", escape(&metadata.title)).unwrap();
+        writeln!(output, "    <link>{}</link>", escape(&metadata.base_url)).unwrap();
+        // The description is mandatory in RSS, so we fall back on the title.
+        writeln!(
+            output,
+            "    <description>{}</description>",
+            escape(metadata.description.as_ref().unwrap_or(&metadata.title))
+        )
+        .unwrap();
+        if let Some(release) = self.releases().first() {
+            writeln!(
+                output,
+                "    <lastBuildDate>{}</lastBuildDate>",
+                rfc2822(release.date())
+            )
+            .unwrap();
+        }
+        for release in self.releases() {
+            writeln!(output, "    <item>").unwrap();
+            writeln!(
+                output,
+                "      <title>{}</title>",
+                escape(&entry_title(release))
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "      <link>{}</link>",
+                escape(&self.entry_link(metadata, release))
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "      <guid isPermaLink=\"false\">{}</guid>",
+                escape(&metadata.release_url(release))
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "      <pubDate>{}</pubDate>",
+                rfc2822(release.date())
+            )
+            .unwrap();
+            if let Some(author) = metadata
+                .author
+                .as_ref()
+                .filter(|author| author.contains('@'))
+            {
+                writeln!(output, "      <author>{}</author>", escape(author)).unwrap();
+            }
+            writeln!(
+                output,
+                "      <description>{}</description>",
+                escape(&self.entry_content(release))
+            )
+            .unwrap();
+            writeln!(output, "    </item>").unwrap();
+        }
+        writeln!(output, "  </channel>").unwrap();
+        writeln!(output, "</rss>").unwrap();
+        output
+    }
+
+    /// Links to the release reference definition, or to the release section of the published
+    /// changelog when there is none.
+    fn entry_link(&self, metadata: &FeedMetadata, release: &Release) -> String {
+        let label = release.version().to_string();
+        self.reference_definitions()
+            .iter()
+            .find(|reference_definition| reference_definition.label().eq_ignore_ascii_case(&label))
+            .map(|reference_definition| reference_definition.dest().to_string())
+            .unwrap_or_else(|| metadata.release_url(release))
+    }
+
+    fn entry_content(&self, release: &Release) -> String {
+        self.changes_to_html(&release_anchor(release.version()), release.changes())
+    }
+}
+
+fn entry_title(release: &Release) -> String {
+    if release.is_yanked() {
+        format!("{} [YANKED]", release.version())
+    } else {
+        release.version().to_string()
+    }
+}
+
+fn rfc3339(date: &NaiveDate) -> String {
+    format!("{}T00:00:00Z", date)
+}
+
+fn rfc2822(date: &NaiveDate) -> String {
+    date.and_hms_opt(0, 0, 0).unwrap().and_utc().to_rfc2822()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Product updates.
+
+## [Unreleased]
+
+### Added
+
+- Not yet.
+
+## [0.2.0] - 2026-02-04 [YANKED]
+
+### Fixed
+
+- The `bull` & co.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    fn metadata() -> FeedMetadata {
+        FeedMetadata::new("Product".into(), "https://example.com/changelog".into())
+    }
+
+    mod to_feed {
+        use super::*;
+
+        #[test]
+        fn should_render_atom_feed() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let metadata = metadata()
+                .with_description("Product updates.".into())
+                .with_author("Product Team".into());
+            assert_eq!(
+                changelog.to_feed(FeedFormat::Atom, &metadata),
+                r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Product</title>
+  <subtitle>Product updates.</subtitle>
+  <id>https://example.com/changelog</id>
+  <link href="https://example.com/changelog"/>
+  <updated>2026-02-04T00:00:00Z</updated>
+  <author>
+    <name>Product Team</name>
+  </author>
+  <entry>
+    <title>0.2.0 [YANKED]</title>
+    <id>https://example.com/changelog#v0.2.0</id>
+    <link href="https://github.com/owner/repo/compare/v0.1.0...v0.2.0"/>
+    <updated>2026-02-04T00:00:00Z</updated>
+    <content type="html">&lt;section class=&quot;change-set change-set-fixed&quot; id=&quot;v0.2.0-fixed&quot;&gt;
+&lt;h3&gt;Fixed&lt;/h3&gt;
+&lt;ul&gt;
+&lt;li&gt;The &lt;code&gt;bull&lt;/code&gt; &amp;amp; co.&lt;/li&gt;
+&lt;/ul&gt;
+&lt;/section&gt;
+</content>
+  </entry>
+  <entry>
+    <title>0.1.0</title>
+    <id>https://example.com/changelog#v0.1.0</id>
+    <link href="https://github.com/owner/repo/releases/tag/v0.1.0"/>
+    <updated>2026-01-01T00:00:00Z</updated>
+    <content type="html">&lt;section class=&quot;change-set change-set-added&quot; id=&quot;v0.1.0-added&quot;&gt;
+&lt;h3&gt;Added&lt;/h3&gt;
+&lt;ul&gt;
+&lt;li&gt;Some bull.&lt;/li&gt;
+&lt;/ul&gt;
+&lt;/section&gt;
+</content>
+  </entry>
+</feed>
+"#
+            );
+        }
+
+        #[test]
+        fn should_render_rss_feed() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let feed = changelog.to_feed(FeedFormat::Rss, &metadata());
+            assert!(feed.starts_with(
+                r#"<?xml version="1.0" encoding="utf-8"?>
+<rss version="2.0">
+  <channel>
+    <title>Product</title>
+    <link>https://example.com/changelog</link>
+    <description>Product</description>
+    <lastBuildDate>Wed, 4 Feb 2026 00:00:00 +0000</lastBuildDate>
+    <item>
+      <title>0.2.0 [YANKED]</title>
+      <link>https://github.com/owner/repo/compare/v0.1.0...v0.2.0</link>
+      <guid isPermaLink="false">https://example.com/changelog#v0.2.0</guid>
+      <pubDate>Wed, 4 Feb 2026 00:00:00 +0000</pubDate>
+      <description>&lt;section"#
+            ));
+            assert!(feed.contains("<pubDate>Thu, 1 Jan 2026 00:00:00 +0000</pubDate>"));
+            assert!(feed.ends_with("  </channel>\n</rss>\n"));
+        }
+
+        #[test]
+        fn should_only_credit_email_authors_in_rss() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let feed = changelog.to_feed(
+                FeedFormat::Rss,
+                &metadata().with_author("team@example.com (Product Team)".into()),
+            );
+            assert_eq!(
+                feed.matches("<author>team@example.com (Product Team)</author>")
+                    .count(),
+                2
+            );
+            let feed = changelog.to_feed(
+                FeedFormat::Rss,
+                &metadata().with_author("Product Team".into()),
+            );
+            assert!(!feed.contains("<author>"));
+        }
+
+        #[test]
+        fn should_match_entry_links_regardless_of_case() {
+            let source = SOURCE
+                .replace("[0.2.0] - 2026-02-04", "[0.2.0-RC.1] - 2026-02-04")
+                .replace("[0.2.0]: ", "[0.2.0-rc.1]: ")
+                .replace("v0.2.0...HEAD", "v0.2.0-rc.1...HEAD")
+                .replace("...v0.2.0\n", "...v0.2.0-rc.1\n");
+            let changelog = Changelog::parse(&source).unwrap();
+            let feed = changelog.to_feed(FeedFormat::Atom, &metadata());
+            assert!(feed.contains(
+                "    <link href=\"https://github.com/owner/repo/compare/v0.1.0...v0.2.0-rc.1\"/>\n"
+            ));
+        }
+
+        #[test]
+        fn should_date_empty_atom_feed_today_and_credit_it() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Nothing yet.
+
+## [Unreleased]
+
+[Unreleased]: https://github.com/owner/repo/commits/HEAD
+",
+            )
+            .unwrap();
+            let before = Utc::now().date_naive();
+            let feed = changelog.to_feed(FeedFormat::Atom, &metadata());
+            let after = Utc::now().date_naive();
+            let updated = |date| format!("  <updated>{}</updated>\n", rfc3339(date));
+            assert!(
+                feed.contains(&updated(&before)) || feed.contains(&updated(&after)),
+                "{}",
+                feed
+            );
+            assert!(!feed.contains("<entry>"));
+            assert!(feed.contains("  <author>\n    <name>Product</name>\n  </author>\n"));
+        }
+
+        #[test]
+        fn should_leave_out_unreleased_changes() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            for format in [FeedFormat::Atom, FeedFormat::Rss] {
+                assert!(!changelog.to_feed(format, &metadata()).contains("Not yet."));
+            }
+        }
+    }
+}
@@ -0,0 +1,443 @@
+use std::fmt::Write;
+
+use pulldown_cmark::{BrokenLink, CowStr, Event, Options, Parser, Tag, TagEnd, html};
+use semver::Version;
+
+use crate::{Change, ChangeSetKind, Changelog, Changes, Release, Unreleased};
+
+/// The stylesheet embedded in standalone pages.
+///
+/// Fragments don't include it, so that they can be styled by the hosting page using
+/// the same class names.
+pub const HTML_STYLESHEET: &str =
+    "body { font-family: sans-serif; max-width: 50rem; margin: 0 auto; padding: 1rem; }
+.release h2 a.anchor { color: #959da5; text-decoration: none; }
+.release.yanked h2 { color: #6a737d; }
+.badge { font-size: 0.75em; padding: 0.1em 0.5em; border-radius: 0.5em; vertical-align: middle; }
+.badge-yanked { background: #d73a49; color: #fff; }
+.change-set h3 { border-left: 0.3em solid; padding-left: 0.4em; }
+.change-set-added h3 { border-color: #28a745; }
+.change-set-changed h3 { border-color: #0366d6; }
+.change-set-deprecated h3 { border-color: #f9a825; }
+.change-set-removed h3 { border-color: #6f42c1; }
+.change-set-fixed h3 { border-color: #17a2b8; }
+.change-set-security h3 { border-color: #d73a49; }";
+
+/// Whether the HTML is rendered as a complete document or as a fragment to embed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtmlLayout {
+    Page,
+    Fragment,
+}
+
+impl TryFrom<&str> for HtmlLayout {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "page" => Ok(Self::Page),
+            "fragment" => Ok(Self::Fragment),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Returns the anchor of a release section.
+///
+/// The anchor only depends on the version, so links to a release remain valid
+/// regardless of its date, yanked status or position in the changelog. The `+` of the build
+/// metadata becomes a `_`, which no version holds, so that no two versions share an anchor.
+pub fn release_anchor(version: &Version) -> String {
+    format!("v{}", version.to_string().replace('+', "_"))
+}
+
+/// The anchor of the unreleased section.
+pub const UNRELEASED_ANCHOR: &str = "unreleased";
+
+impl<'source> Changelog<'source> {
+    /// Renders the changelog as HTML.
+    ///
+    /// Every release gets its own section, with an `id` derived from its version. The
+    /// entries are rendered as inline markdown, and links to reference definitions of the
+    /// changelog are resolved.
+    pub fn to_html(&self, layout: HtmlLayout) -> String {
+        let mut output = String::new();
+        if layout == HtmlLayout::Page {
+            writeln!(
+                output,
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Changelog</title>\n<style>\n{}\n</style>\n</head>\n<body>",
+                HTML_STYLESHEET
+            )
+            .unwrap();
+        }
+        writeln!(output, "<article class=\"changelog\">").unwrap();
+        writeln!(output, "<h1>Changelog</h1>").unwrap();
+        let intro = self.title().intro();
+        if !intro.is_empty() {
+            output.push_str(&self.markdown_to_html(intro, false));
+        }
+        if let Some(unreleased) = self.unreleased() {
+            self.write_unreleased(&mut output, unreleased);
+        }
+        for release in self.releases() {
+            self.write_release(&mut output, release);
+        }
+        writeln!(output, "</article>").unwrap();
+        if layout == HtmlLayout::Page {
+            writeln!(output, "</body>\n</html>").unwrap();
+        }
+        output
+    }
+
+    fn write_unreleased(&self, output: &mut String, unreleased: &Unreleased) {
+        writeln!(
+            output,
+            "<section class=\"release unreleased\" id=\"{}\">",
+            UNRELEASED_ANCHOR
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "<h2>{} <a class=\"anchor\" href=\"#{}\">#</a></h2>",
+            self.heading_label("Unreleased"),
+            UNRELEASED_ANCHOR
+        )
+        .unwrap();
+        self.write_changes(output, UNRELEASED_ANCHOR, unreleased.changes());
+        writeln!(output, "</section>").unwrap();
+    }
+
+    fn write_release(&self, output: &mut String, release: &Release) {
+        let anchor = release_anchor(release.version());
+        let class = if release.is_yanked() {
+            "release yanked"
+        } else {
+            "release"
+        };
+        writeln!(output, "<section class=\"{}\" id=\"{}\">", class, anchor).unwrap();
+        write!(
+            output,
+            "<h2>{} - <time datetime=\"{}\">{}</time>",
+            self.heading_label(&release.version().to_string()),
+            release.date(),
+            release.date()
+        )
+        .unwrap();
+        if release.is_yanked() {
+            write!(output, " <span class=\"badge badge-yanked\">YANKED</span>").unwrap();
+        }
+        writeln!(
+            output,
+            " <a class=\"anchor\" href=\"#{}\">#</a></h2>",
+            anchor
+        )
+        .unwrap();
+        self.write_changes(output, &anchor, release.changes());
+        writeln!(output, "</section>").unwrap();
+    }
+
+    /// Renders the change sets of a section, with anchors prefixed by the one of the section.
+    pub(crate) fn changes_to_html(&self, anchor: &str, changes: &Changes) -> String {
+        let mut output = String::new();
+        self.write_changes(&mut output, anchor, changes);
+        output
+    }
+
+    fn write_changes(&self, output: &mut String, anchor: &str, changes: &Changes) {
+        for change_set in changes.iter() {
+            let kind = kind_class(change_set.kind());
+            writeln!(
+                output,
+                "<section class=\"change-set change-set-{}\" id=\"{}-{}\">",
+                kind, anchor, kind
+            )
+            .unwrap();
+            writeln!(output, "<h3>{}</h3>\n<ul>", change_set.kind()).unwrap();
+            for change in change_set.items() {
+                self.write_change(output, change);
+            }
+            writeln!(output, "</ul>\n</section>").unwrap();
+        }
+    }
+
+    fn write_change(&self, output: &mut String, change: &Change) {
+        let html = self.markdown_to_html(change.text(), true);
+        writeln!(output, "<li>{}</li>", html.trim_end()).unwrap();
+    }
+
+    /// The label of a section heading, linked to its reference definition when there is one.
+    fn heading_label(&self, label: &str) -> String {
+        match self
+            .reference_definition_dest(label)
+            .filter(|dest| is_safe_url(dest))
+        {
+            Some(dest) => format!("<a href=\"{}\">{}</a>", escape(dest), escape(label)),
+            None => escape(label),
+        }
+    }
+
+    fn reference_definition_dest(&self, label: &str) -> Option<&str> {
+        self.reference_definitions()
+            .iter()
+            .find(|reference_definition| reference_definition.label().eq_ignore_ascii_case(label))
+            .map(|reference_definition| reference_definition.dest())
+    }
+
+    /// Renders markdown through pulldown-cmark.
+    ///
+    /// The reference definitions of the changelog are made available to the snippet, since they
+    /// are defined at the bottom of the document rather than next to the entry. When `inline` is
+    /// set, a lone paragraph is unwrapped so that simple entries don't end up in a `<p>`. Raw HTML
+    /// is escaped rather than passed through, and links and images to anything else than web
+    /// pages, email addresses and relative paths are reduced to their text, as changelogs are
+    /// published as-is.
+    fn markdown_to_html(&self, markdown: &str, inline: bool) -> String {
+        let callback = |link: BrokenLink<'_>| {
+            self.reference_definition_dest(&link.reference)
+                .map(|dest| (CowStr::from(dest.to_string()), CowStr::from("")))
+        };
+        // Whether each open link or image was dropped, so that its end is dropped as well.
+        let mut dropped = vec![];
+        let mut events: Vec<Event<'_>> =
+            Parser::new_with_broken_link_callback(markdown, Options::empty(), Some(callback))
+                .filter_map(|event| match event {
+                    Event::Html(html) | Event::InlineHtml(html) => Some(Event::Text(html)),
+                    Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. })
+                        if !is_safe_url(&dest_url) =>
+                    {
+                        dropped.push(true);
+                        None
+                    }
+                    event @ Event::Start(Tag::Link { .. } | Tag::Image { .. }) => {
+                        dropped.push(false);
+                        Some(event)
+                    }
+                    Event::End(end @ (TagEnd::Link | TagEnd::Image)) => {
+                        (!dropped.pop().unwrap_or_default()).then_some(Event::End(end))
+                    }
+                    event => Some(event),
+                })
+                .collect();
+        let paragraphs = events
+            .iter()
+            .filter(|event| matches!(event, Event::Start(Tag::Paragraph)))
+            .count();
+        if inline
+            && paragraphs == 1
+            && matches!(events.first(), Some(Event::Start(Tag::Paragraph)))
+            && matches!(events.last(), Some(Event::End(TagEnd::Paragraph)))
+        {
+            events.remove(0);
+            events.pop();
+        }
+        let mut output = String::new();
+        html::push_html(&mut output, events.into_iter());
+        output
+    }
+}
+
+// Whether a link destination is a web page, an email address or a relative path. Browsers
+// ignore the whitespace and control characters of a scheme, like in `java\tscript:`.
+fn is_safe_url(dest: &str) -> bool {
+    let dest: String = dest
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect();
+    // A colon after a slash, question mark or hash is part of a relative path.
+    match dest.find([':', '/', '?', '#']) {
+        Some(index) if dest[index..].starts_with(':') => {
+            let scheme = dest[..index].to_ascii_lowercase();
+            matches!(scheme.as_str(), "http" | "https" | "mailto")
+        }
+        _ => true,
+    }
+}
+
+fn kind_class(kind: ChangeSetKind) -> String {
+    kind.to_string().to_lowercase()
+}
+
+/// Escapes text so that it can be used both as element content and attribute value.
+pub(crate) fn escape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Some *intro*.
+
+## [Unreleased]
+
+### Added
+
+- Some `new` bull, see [0.1.0].
+
+## [0.2.0] - 2026-02-04 [YANKED]
+
+### Security
+
+- The <bull> & co.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    mod release_anchor {
+        use super::*;
+
+        #[test]
+        fn should_prefix_the_version() {
+            assert_eq!(release_anchor(&Version::new(1, 2, 3)), "v1.2.3");
+        }
+
+        #[test]
+        fn should_replace_build_metadata_separator() {
+            assert_eq!(
+                release_anchor(&Version::parse("1.2.3-rc.1+build.5").unwrap()),
+                "v1.2.3-rc.1_build.5"
+            );
+        }
+
+        #[test]
+        fn should_tell_build_metadata_from_pre_release() {
+            assert_ne!(
+                release_anchor(&Version::parse("1.2.3-rc.1+build.5").unwrap()),
+                release_anchor(&Version::parse("1.2.3-rc.1-build.5").unwrap())
+            );
+        }
+    }
+
+    mod is_safe_url {
+        use super::*;
+
+        #[test]
+        fn should_only_allow_web_email_and_relative_destinations() {
+            for dest in [
+                "https://example.com",
+                "HTTP://example.com",
+                "mailto:bull@example.com",
+                "docs/bull.md",
+                "#v1.0.0",
+                "./a:b",
+            ] {
+                assert!(is_safe_url(dest), "{}", dest);
+            }
+            for dest in [
+                "javascript:alert(1)",
+                "JAVA\tSCRIPT:alert(1)",
+                " data:text/html,bull",
+                "vbscript:bull",
+            ] {
+                assert!(!is_safe_url(dest), "{}", dest);
+            }
+        }
+    }
+
+    mod to_html {
+        use super::*;
+
+        #[test]
+        fn should_render_a_fragment() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            assert_eq!(
+                changelog.to_html(HtmlLayout::Fragment),
+                r##"<article class="changelog">
+<h1>Changelog</h1>
+<p>Some <em>intro</em>.</p>
+<section class="release unreleased" id="unreleased">
+<h2><a href="https://github.com/owner/repo/compare/v0.2.0...HEAD">Unreleased</a> <a class="anchor" href="#unreleased">#</a></h2>
+<section class="change-set change-set-added" id="unreleased-added">
+<h3>Added</h3>
+<ul>
+<li>Some <code>new</code> bull, see <a href="https://github.com/owner/repo/releases/tag/v0.1.0">0.1.0</a>.</li>
+</ul>
+</section>
+</section>
+<section class="release yanked" id="v0.2.0">
+<h2><a href="https://github.com/owner/repo/compare/v0.1.0...v0.2.0">0.2.0</a> - <time datetime="2026-02-04">2026-02-04</time> <span class="badge badge-yanked">YANKED</span> <a class="anchor" href="#v0.2.0">#</a></h2>
+<section class="change-set change-set-security" id="v0.2.0-security">
+<h3>Security</h3>
+<ul>
+<li>The &lt;bull&gt; &amp; co.</li>
+</ul>
+</section>
+</section>
+<section class="release" id="v0.1.0">
+<h2><a href="https://github.com/owner/repo/releases/tag/v0.1.0">0.1.0</a> - <time datetime="2026-01-01">2026-01-01</time> <a class="anchor" href="#v0.1.0">#</a></h2>
+<section class="change-set change-set-added" id="v0.1.0-added">
+<h3>Added</h3>
+<ul>
+<li>Some bull.</li>
+</ul>
+</section>
+</section>
+</article>
+"##
+            );
+        }
+
+        #[test]
+        fn should_drop_links_and_images_to_unsafe_destinations() {
+            let changelog = Changelog::parse(
+                r"# Changelog
+
+Some intro.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some [bull](javascript:alert(1)), ![bull](data:image/png;base64,AAAA) and [more][evil].
+- Some [bull](https://example.com), [docs](docs/bull.md) and [mail](mailto:bull@example.com).
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+[evil]: data:text/html,bull
+",
+            )
+            .unwrap();
+            let html = changelog.to_html(HtmlLayout::Fragment);
+            assert!(
+                html.contains("<li>Some bull, bull and more.</li>"),
+                "{}",
+                html
+            );
+            assert!(
+                html.contains("<li>Some <a href=\"https://example.com\">bull</a>, <a href=\"docs/bull.md\">docs</a> and <a href=\"mailto:bull@example.com\">mail</a>.</li>"),
+                "{}",
+                html
+            );
+        }
+
+        #[test]
+        fn should_render_a_standalone_page() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let html = changelog.to_html(HtmlLayout::Page);
+            assert!(html.starts_with("<!DOCTYPE html>\n"));
+            assert!(html.contains(HTML_STYLESHEET));
+            assert!(html.contains(&changelog.to_html(HtmlLayout::Fragment)));
+            assert!(html.ends_with("</body>\n</html>\n"));
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt::Write;
+
+use crate::{Change, Changelog, Changes, Release, Unreleased};
+
+/// The introduction paragraph suggested by [Keep a Changelog](https://keepachangelog.com/en/1.1.0/).
+pub const KEEP_A_CHANGELOG_INTRO: &str =
+    "All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).";
+
+impl<'source> Changelog<'source> {
+    /// Renders the changelog back into Keep a Changelog markdown.
+    ///
+    /// The output is canonical: one blank line between blocks, `-` list markers and the
+    /// reference definitions grouped at the end of the document.
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        writeln!(output, "# Changelog\n").unwrap();
+        let intro = self.title().intro();
+        if !intro.is_empty() {
+            writeln!(output, "{}\n", intro).unwrap();
+        }
+        if let Some(unreleased) = self.unreleased() {
+            write_unreleased(&mut output, unreleased);
+        }
+        for release in self.releases() {
+            write_release(&mut output, release);
+        }
+        for reference_definition in self.reference_definitions() {
+            writeln!(
+                output,
+                "[{}]: {}",
+                reference_definition.label(),
+                reference_definition.dest()
+            )
+            .unwrap();
+        }
+        // Only a single trailing new line, even when there are no reference definitions.
+        while output.ends_with("\n\n") {
+            output.pop();
+        }
+        output
+    }
+}
+
+fn write_unreleased(output: &mut String, unreleased: &Unreleased) {
+    writeln!(output, "## [Unreleased]\n").unwrap();
+    write_changes(output, unreleased.changes());
+}
+
+fn write_release(output: &mut String, release: &Release) {
+    write!(output, "## [{}] - {}", release.version(), release.date()).unwrap();
+    if release.is_yanked() {
+        write!(output, " [YANKED]").unwrap();
+    }
+    writeln!(output, "\n").unwrap();
+    write_changes(output, release.changes());
+}
+
+fn write_changes(output: &mut String, changes: &Changes) {
+    for change_set in changes.iter() {
+        writeln!(output, "### {}\n", change_set.kind()).unwrap();
+        for change in change_set.items() {
+            write_change(output, change);
+        }
+        writeln!(output).unwrap();
+    }
+}
+
+fn write_change(output: &mut String, change: &Change) {
+    let mut lines = change.text().lines();
+    writeln!(output, "- {}", lines.next().unwrap_or_default()).unwrap();
+    for line in lines {
+        // Continuation lines have to be indented to remain part of the item. Parsed entries
+        // already are, since their text is taken verbatim from the source.
+        if line.is_empty() || line.starts_with("  ") {
+            writeln!(output, "{}", line).unwrap();
+        } else {
+            writeln!(output, "  {}", line).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod to_markdown {
+        use super::*;
+
+        #[test]
+        fn should_render_a_parsed_changelog_verbatim() {
+            let source = r"# Changelog
+
+This is a mfking changelog y'all.
+
+## [Unreleased]
+
+### Added
+
+- Some `new` bull.
+
+## [0.2.0] - 2026-02-04 [YANKED]
+
+### Changed
+
+- The bull, now
+  on two lines.
+
+### Removed
+
+- The bull.
+- The other bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+            let changelog = Changelog::parse(source).unwrap();
+            assert_eq!(changelog.to_markdown(), source);
+        }
+
+        #[test]
+        fn should_normalize_spacing() {
+            let source = r"# Changelog
+Intro.
+## [0.1.0] - 2026-01-01
+### Added
+* Some bull.
+* More bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0";
+            let changelog = Changelog::parse(source).unwrap();
+            assert_eq!(
+                changelog.to_markdown(),
+                r"# Changelog
+
+Intro.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+- More bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+"
+            );
+        }
+    }
+}
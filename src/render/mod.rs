@@ -0,0 +1,8 @@
+mod feed;
+mod html;
+mod markdown;
+
+pub use feed::*;
+pub(crate) use html::escape;
+pub use html::*;
+pub use markdown::*;
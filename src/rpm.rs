@@ -0,0 +1,147 @@
+use std::fmt::Write;
+
+use crate::Changelog;
+
+/// The package information of an RPM `%changelog` that cannot be inferred from the changelog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpmConfig {
+    packager: String,
+    release: Option<String>,
+}
+
+impl RpmConfig {
+    /// The packager is expected in the `Full Name <email@example.com>` form.
+    pub fn new(packager: String) -> Self {
+        Self {
+            packager,
+            release: None,
+        }
+    }
+
+    /// The `Release` of the spec file, appended to the versions of the entries.
+    pub fn with_release(mut self, release: String) -> Self {
+        self.release = Some(release);
+        self
+    }
+}
+
+impl<'source> Changelog<'source> {
+    /// Renders the releases of the changelog as the `%changelog` section of an RPM spec file.
+    ///
+    /// Every entry is prefixed with the kind of its change set. Since RPM versions sort
+    /// pre-releases with `~`, the `-` of semantic versions are converted. The unreleased
+    /// section is left out.
+    pub fn to_rpm_changelog(&self, config: &RpmConfig) -> String {
+        let mut output = String::new();
+        writeln!(output, "%changelog").unwrap();
+        for (index, release) in self.releases().iter().enumerate() {
+            if index > 0 {
+                writeln!(output).unwrap();
+            }
+            write!(
+                output,
+                "* {} {} - {}",
+                release.date().format("%a %b %d %Y"),
+                rpm_escape(&config.packager),
+                release.version().to_string().replace('-', "~")
+            )
+            .unwrap();
+            if let Some(rpm_release) = &config.release {
+                write!(output, "-{}", rpm_escape(rpm_release)).unwrap();
+            }
+            writeln!(output).unwrap();
+            for change_set in release.changes().iter() {
+                for change in change_set.items() {
+                    let mut lines = change.text().lines();
+                    writeln!(
+                        output,
+                        "- {}: {}",
+                        change_set.kind(),
+                        rpm_escape(lines.next().unwrap_or_default())
+                    )
+                    .unwrap();
+                    for line in lines.filter(|line| !line.trim().is_empty()) {
+                        writeln!(output, "  {}", rpm_escape(line.trim())).unwrap();
+                    }
+                }
+            }
+        }
+        output
+    }
+}
+
+// A lone `%` would be expanded as a macro by rpmbuild.
+fn rpm_escape(text: &str) -> String {
+    text.replace('%', "%%")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Not yet.
+
+## [0.2.0] - 2026-02-04
+
+### Changed
+
+- The bull, now
+  on two lines.
+
+### Removed
+
+- 100% of the bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.2.0...HEAD
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    mod to_rpm_changelog {
+        use super::*;
+
+        #[test]
+        fn should_render_releases() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let config = RpmConfig::new("Bull Master <bull@example.com>".into());
+            assert_eq!(
+                changelog.to_rpm_changelog(&config),
+                r"%changelog
+* Wed Feb 04 2026 Bull Master <bull@example.com> - 0.2.0
+- Changed: The bull, now
+  on two lines.
+- Removed: 100%% of the bull.
+
+* Thu Jan 01 2026 Bull Master <bull@example.com> - 0.1.0
+- Added: Some bull.
+"
+            );
+        }
+
+        #[test]
+        fn should_append_release() {
+            let changelog = Changelog::parse(SOURCE).unwrap();
+            let config =
+                RpmConfig::new("Bull Master <bull@example.com>".into()).with_release("1".into());
+            assert!(
+                changelog
+                    .to_rpm_changelog(&config)
+                    .contains("* Thu Jan 01 2026 Bull Master <bull@example.com> - 0.1.0-1\n")
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use schemars::{Schema, schema_for};
+
+use crate::{Changelog, ChangelogDocument};
+
+/// The JSON schema of the documents produced by [Changelog::export].
+pub fn export_schema() -> Schema {
+    schema_for!(Changelog)
+}
+
+/// The JSON schema of the documents accepted by [crate::import].
+pub fn import_schema() -> Schema {
+    schema_for!(ChangelogDocument)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    fn change_set_kinds(schema: &Value) -> Vec<Value> {
+        let mut kinds: Vec<_> = schema["$defs"]["ChangeSet"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["properties"]["kind"]["const"].clone())
+            .collect();
+        kinds.sort_by_key(|kind| kind.to_string());
+        kinds
+    }
+
+    mod export_schema {
+        use super::*;
+
+        #[test]
+        fn should_describe_the_changelog_document() {
+            let schema = export_schema().to_value();
+            assert_eq!(schema["title"], "Changelog");
+            assert_eq!(schema["properties"]["releases"]["type"], "array");
+            assert!(schema["properties"].get("source").is_none());
+            let release = &schema["$defs"]["Release"];
+            assert_eq!(release["properties"]["yanked"]["type"], "boolean");
+            assert_eq!(schema["$defs"]["SemVer"]["type"], "string");
+            assert_eq!(release["properties"]["date"]["format"], "date");
+            assert_eq!(
+                schema["$defs"]["ReferenceDefinition"]["properties"]["dest"]["type"],
+                "string"
+            );
+            assert_eq!(
+                change_set_kinds(&schema),
+                vec![
+                    json!("added"),
+                    json!("changed"),
+                    json!("deprecated"),
+                    json!("fixed"),
+                    json!("removed"),
+                    json!("security")
+                ]
+            );
+        }
+    }
+
+    mod import_schema {
+        use super::*;
+
+        #[test]
+        fn should_describe_the_import_document() {
+            let schema = import_schema().to_value();
+            assert_eq!(schema["title"], "ChangelogDocument");
+            assert_eq!(schema["required"], json!(["repository"]));
+            assert_eq!(schema["additionalProperties"], false);
+            let release = &schema["$defs"]["ReleaseDocument"];
+            assert_eq!(release["properties"]["yanked"]["type"], "boolean");
+            assert_eq!(release["required"], json!(["version", "date", "changes"]));
+        }
+    }
+}
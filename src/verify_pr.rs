@@ -0,0 +1,225 @@
+use std::{error::Error, fmt::Display};
+
+use semver::Version;
+
+use crate::{ChangeSetKind, Changelog, ChangelogDifference, ChangelogSection, diff};
+
+/// Verifies that a pull request updated the changelog according to policy.
+///
+/// The head revision must add at least one entry to the unreleased section, or cut a new
+/// release. Released sections are frozen: their entries and dates cannot change, and they
+/// cannot be removed. Yanking a release is the only allowed edit. Entries cannot be
+/// deleted either, unless they are moved from the unreleased section into a new release.
+pub fn verify_pr(base: &Changelog, head: &Changelog) -> Result<(), PullRequestPolicyError> {
+    let differences = diff(base, head);
+    let added_releases: Vec<&Version> = differences
+        .iter()
+        .filter_map(|difference| match difference {
+            ChangelogDifference::ReleaseAdded(version) => Some(version),
+            _ => None,
+        })
+        .collect();
+
+    for difference in &differences {
+        match difference {
+            ChangelogDifference::ReleaseRemoved(version) => {
+                return Err(PullRequestPolicyError::ReleaseDeleted(version.clone()));
+            }
+            ChangelogDifference::DateChanged { version, .. } => {
+                return Err(PullRequestPolicyError::DateChanged(version.clone()));
+            }
+            ChangelogDifference::EntryAdded {
+                section: ChangelogSection::Release(version),
+                ..
+            } => {
+                return Err(PullRequestPolicyError::ReleaseEdited(version.clone()));
+            }
+            ChangelogDifference::EntryRemoved {
+                section: ChangelogSection::Release(version),
+                ..
+            } => {
+                return Err(PullRequestPolicyError::ReleaseEdited(version.clone()));
+            }
+            ChangelogDifference::EntryRemoved {
+                section: ChangelogSection::Unreleased,
+                kind,
+                text,
+            } if !released_in(head, &added_releases, *kind, text) => {
+                return Err(PullRequestPolicyError::EntryDeleted(*kind, text.clone()));
+            }
+            _ => {}
+        }
+    }
+
+    let has_new_entry = differences.iter().any(|difference| {
+        matches!(
+            difference,
+            ChangelogDifference::EntryAdded {
+                section: ChangelogSection::Unreleased,
+                ..
+            }
+        )
+    });
+    if !has_new_entry && added_releases.is_empty() {
+        return Err(PullRequestPolicyError::NoNewEntry);
+    }
+    Ok(())
+}
+
+// Whether the entry shows up in one of the releases added by the head revision.
+fn released_in(head: &Changelog, versions: &[&Version], kind: ChangeSetKind, text: &str) -> bool {
+    head.releases()
+        .iter()
+        .filter(|release| versions.contains(&release.version()))
+        .flat_map(|release| release.changes().iter())
+        .filter(|change_set| change_set.kind() == kind)
+        .flat_map(|change_set| change_set.items())
+        .any(|change| change.text() == text)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PullRequestPolicyError {
+    NoNewEntry,
+    ReleaseEdited(Version),
+    ReleaseDeleted(Version),
+    DateChanged(Version),
+    EntryDeleted(ChangeSetKind, String),
+}
+
+impl Display for PullRequestPolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PullRequestPolicyError::NoNewEntry => {
+                write!(
+                    f,
+                    "expected at least one new entry in the unreleased section"
+                )
+            }
+            PullRequestPolicyError::ReleaseEdited(version) => {
+                write!(f, "the entries of released version {} were edited", version)
+            }
+            PullRequestPolicyError::ReleaseDeleted(version) => {
+                write!(f, "released version {} was deleted", version)
+            }
+            PullRequestPolicyError::DateChanged(version) => {
+                write!(f, "the date of released version {} was changed", version)
+            }
+            PullRequestPolicyError::EntryDeleted(kind, text) => {
+                write!(f, "the unreleased {} entry {:?} was deleted", kind, text)
+            }
+        }
+    }
+}
+
+impl Error for PullRequestPolicyError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BASE: &str = r"# Changelog
+
+Some intro.
+
+## [Unreleased]
+
+### Added
+
+- Some `new` bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[Unreleased]: https://github.com/owner/repo/compare/v0.1.0...HEAD
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    fn verify(head: &str) -> Result<(), PullRequestPolicyError> {
+        let base = Changelog::parse(BASE).unwrap();
+        let head = Changelog::parse(head).unwrap();
+        verify_pr(&base, &head)
+    }
+
+    mod verify_pr {
+        use super::*;
+
+        #[test]
+        fn should_accept_new_unreleased_entry() {
+            let head = BASE.replace(
+                "- Some `new` bull.\n",
+                "- Some `new` bull.\n- Some newer bull.\n",
+            );
+            assert_eq!(verify(&head), Ok(()));
+        }
+
+        #[test]
+        fn should_accept_new_release() {
+            let head = r"# Changelog
+
+Some intro.
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- Some `new` bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.2.0]: https://github.com/owner/repo/compare/v0.1.0...v0.2.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+            assert_eq!(verify(head), Ok(()));
+        }
+
+        #[test]
+        fn should_accept_yanking_a_release() {
+            let head = BASE
+                .replace("2026-01-01", "2026-01-01 [YANKED]")
+                .replace("- Some `new` bull.\n", "- Some `new` bull.\n- The yank.\n");
+            assert_eq!(verify(&head), Ok(()));
+        }
+
+        #[test]
+        fn should_error_without_new_entry() {
+            assert_eq!(verify(BASE), Err(PullRequestPolicyError::NoNewEntry));
+        }
+
+        #[test]
+        fn should_error_with_edited_release() {
+            let head = BASE.replace("- Some bull.\n", "- Some other bull.\n");
+            assert_eq!(
+                verify(&head),
+                Err(PullRequestPolicyError::ReleaseEdited(Version::new(0, 1, 0)))
+            );
+        }
+
+        #[test]
+        fn should_error_with_changed_date() {
+            let head = BASE.replace("2026-01-01", "2026-01-02");
+            assert_eq!(
+                verify(&head),
+                Err(PullRequestPolicyError::DateChanged(Version::new(0, 1, 0)))
+            );
+        }
+
+        #[test]
+        fn should_error_with_deleted_entry() {
+            let head = BASE.replace("- Some `new` bull.\n", "- Some newer bull.\n");
+            assert_eq!(
+                verify(&head),
+                Err(PullRequestPolicyError::EntryDeleted(
+                    ChangeSetKind::Added,
+                    "Some `new` bull.".to_string()
+                ))
+            );
+        }
+    }
+}
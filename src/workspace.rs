@@ -0,0 +1,314 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use changelog_ast::{Encoding, LineColumn, LineIndex};
+use toml::{Table, Value};
+
+use crate::{
+    ChangelogCheckError, DecodeError, DecodedText, Manifest, ManifestError, check_package,
+};
+
+/// The file name of the changelog expected at the root of every workspace member.
+pub const CHANGELOG_FILE_NAME: &str = "CHANGELOG.md";
+
+/// Lists the directories of the members of a cargo workspace, sorted.
+///
+/// Members are declared with paths or glob patterns relative to the workspace root, and
+/// can be excluded again. The root package, if any, is a member as well.
+pub fn workspace_members(manifest: &Path) -> Result<Vec<PathBuf>, ManifestError> {
+    let content = std::fs::read_to_string(manifest)?;
+    let table: Table = content
+        .parse()
+        .map_err(|err| ManifestError::InvalidContent(manifest.to_path_buf(), Box::new(err)))?;
+    let Some(workspace) = table.get("workspace") else {
+        return Err(ManifestError::MissingWorkspace(manifest.to_path_buf()));
+    };
+    let root = manifest.parent().unwrap_or(Path::new(""));
+
+    let patterns = |key| match workspace.get(key) {
+        Some(Value::Array(patterns)) => patterns.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+    };
+    let mut excluded = vec![];
+    for pattern in patterns("exclude") {
+        excluded.extend(expand(root, pattern)?);
+    }
+    let mut members = vec![];
+    if table.contains_key("package") {
+        members.push(root.to_path_buf());
+    }
+    for pattern in patterns("members") {
+        for member in expand(root, pattern)? {
+            // Globs also match stray files, like a README in the crates directory.
+            if member.join("Cargo.toml").is_file()
+                && !excluded.contains(&member)
+                && !members.contains(&member)
+            {
+                members.push(member);
+            }
+        }
+    }
+    members.sort();
+    Ok(members)
+}
+
+fn expand(root: &Path, pattern: &str) -> Result<Vec<PathBuf>, ManifestError> {
+    let pattern = root.join(pattern);
+    let paths = glob::glob(&pattern.to_string_lossy())
+        .map_err(|err| ManifestError::InvalidContent(pattern.clone(), Box::new(err)))?;
+    // Unreadable directories are skipped, like cargo does.
+    Ok(paths.filter_map(Result::ok).collect())
+}
+
+/// The outcome of checking the changelog of a workspace member.
+#[derive(Debug)]
+pub struct MemberCheck {
+    changelog: PathBuf,
+    result: Result<(), MemberCheckError>,
+    /// Where the error is in the changelog, if it has a location.
+    position: Option<LineColumn>,
+}
+
+impl MemberCheck {
+    pub fn changelog(&self) -> &Path {
+        &self.changelog
+    }
+
+    pub fn result(&self) -> &Result<(), MemberCheckError> {
+        &self.result
+    }
+
+    pub fn position(&self) -> Option<LineColumn> {
+        self.position
+    }
+}
+
+/// Checks the changelogs of all the members of a cargo workspace, in parallel.
+///
+/// Each member is expected to hold its changelog at its root. Its releases may be tagged
+/// with its package name as prefix. The outcomes are returned in the order of the members.
+///
+/// Changelogs are decoded like any other, and those that aren't valid UTF-8 as Latin-1 when
+/// asked for.
+pub fn check_workspace(manifest: &Path, latin1: bool) -> Result<Vec<MemberCheck>, ManifestError> {
+    let members = workspace_members(manifest)?;
+    let checks = std::thread::scope(|scope| {
+        let handles: Vec<_> = members
+            .iter()
+            .map(|member| {
+                let changelog = member.join(CHANGELOG_FILE_NAME);
+                (
+                    changelog.clone(),
+                    scope.spawn(move || check_member(&changelog, latin1)),
+                )
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|(changelog, handle)| {
+                // A panic only fails its member, it has been reported on stderr already.
+                let (result, position) = handle
+                    .join()
+                    .unwrap_or((Err(MemberCheckError::Panicked), None));
+                MemberCheck {
+                    changelog,
+                    result,
+                    position,
+                }
+            })
+            .collect()
+    });
+    Ok(checks)
+}
+
+fn check_member(
+    changelog: &Path,
+    latin1: bool,
+) -> (Result<(), MemberCheckError>, Option<LineColumn>) {
+    let content = match DecodedText::read(changelog, latin1) {
+        Ok(content) => content.into_text(),
+        Err(err) => return (Err(err.into()), None),
+    };
+    let package = Manifest::adjacent(changelog)
+        .and_then(|manifest| manifest.package_name().ok())
+        .flatten();
+    match check_package(&content, package.as_deref()) {
+        Ok(_) => (Ok(()), None),
+        Err(err) => {
+            let position = err
+                .range(&content)
+                .map(|range| LineIndex::new(&content).line_column(range.start, Encoding::Utf8));
+            (Err(err.into()), position)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MemberCheckError {
+    Read(DecodeError),
+    Check(ChangelogCheckError),
+    /// The check panicked, which is a bug.
+    Panicked,
+}
+
+impl From<DecodeError> for MemberCheckError {
+    fn from(value: DecodeError) -> Self {
+        Self::Read(value)
+    }
+}
+
+impl From<ChangelogCheckError> for MemberCheckError {
+    fn from(value: ChangelogCheckError) -> Self {
+        Self::Check(value)
+    }
+}
+
+impl Display for MemberCheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemberCheckError::Read(err) => write!(f, "unable to read changelog: {}", err),
+            MemberCheckError::Check(err) => write!(f, "{}", err),
+            MemberCheckError::Panicked => {
+                write!(f, "unable to check changelog: the check panicked")
+            }
+        }
+    }
+}
+
+impl Error for MemberCheckError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VALID: &str = r"# Changelog
+
+Some intro.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+    // Each test gets its own directory, as they run concurrently.
+    fn write_workspace(test: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("changelog-workspace-{}", test));
+        let _ = std::fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        root
+    }
+
+    mod workspace_members {
+        use super::*;
+
+        #[test]
+        fn should_expand_globs_and_exclusions() {
+            let root = write_workspace(
+                "members",
+                &[
+                    (
+                        "Cargo.toml",
+                        "[package]\nname = \"root\"\n\n[workspace]\nmembers = [\"crates/*\", \"tools/cli\"]\nexclude = [\"crates/legacy\"]\n",
+                    ),
+                    ("crates/bull/Cargo.toml", "[package]\nname = \"bull\"\n"),
+                    ("crates/legacy/Cargo.toml", "[package]\nname = \"legacy\"\n"),
+                    ("crates/README.md", "Not a crate."),
+                    ("tools/cli/Cargo.toml", "[package]\nname = \"cli\"\n"),
+                ],
+            );
+            assert_eq!(
+                workspace_members(&root.join("Cargo.toml")).unwrap(),
+                vec![
+                    root.clone(),
+                    root.join("crates/bull"),
+                    root.join("tools/cli")
+                ]
+            );
+        }
+
+        #[test]
+        fn should_error_without_workspace() {
+            let root = write_workspace("none", &[("Cargo.toml", "[package]\nname = \"bull\"\n")]);
+            let result = workspace_members(&root.join("Cargo.toml"));
+            assert!(
+                matches!(result, Err(ManifestError::MissingWorkspace(_))),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    mod check_workspace {
+        use super::*;
+
+        #[test]
+        fn should_report_every_member() {
+            let root = write_workspace(
+                "check",
+                &[
+                    ("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n"),
+                    ("crates/a/Cargo.toml", "[package]\nname = \"a\"\n"),
+                    ("crates/a/CHANGELOG.md", VALID),
+                    ("crates/b/Cargo.toml", "[package]\nname = \"b\"\n"),
+                    ("crates/b/CHANGELOG.md", "Not a changelog."),
+                    ("crates/c/Cargo.toml", "[package]\nname = \"c\"\n"),
+                ],
+            );
+            let checks = check_workspace(&root.join("Cargo.toml"), false).unwrap();
+            assert_eq!(checks.len(), 3);
+            assert_eq!(
+                checks[0].changelog(),
+                root.join("crates/a").join(CHANGELOG_FILE_NAME)
+            );
+            assert!(checks[0].result().is_ok());
+            assert_eq!(checks[1].position(), Some(LineColumn::new(1, 1)));
+            assert!(
+                matches!(checks[1].result(), Err(MemberCheckError::Check(_))),
+                "{:?}",
+                checks[1]
+            );
+            assert!(
+                matches!(checks[2].result(), Err(MemberCheckError::Read(_))),
+                "{:?}",
+                checks[2]
+            );
+        }
+
+        #[test]
+        fn should_decode_changelogs() {
+            let root = write_workspace(
+                "decode",
+                &[
+                    ("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n"),
+                    ("crates/a/Cargo.toml", "[package]\nname = \"a\"\n"),
+                    ("crates/b/Cargo.toml", "[package]\nname = \"b\"\n"),
+                ],
+            );
+            let bom = [b"\xEF\xBB\xBF", VALID.as_bytes()].concat();
+            std::fs::write(root.join("crates/a").join(CHANGELOG_FILE_NAME), bom).unwrap();
+            let utf16: Vec<u8> = [0xFEFF_u16]
+                .into_iter()
+                .chain(VALID.encode_utf16())
+                .flat_map(u16::to_le_bytes)
+                .collect();
+            std::fs::write(root.join("crates/b").join(CHANGELOG_FILE_NAME), utf16).unwrap();
+            let checks = check_workspace(&root.join("Cargo.toml"), false).unwrap();
+            assert!(
+                checks.iter().all(|check| check.result().is_ok()),
+                "{:?}",
+                checks
+            );
+        }
+    }
+}
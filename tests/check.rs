@@ -0,0 +1,175 @@
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+// Version 0.2.0 is missing, which fails the version-gap lint.
+const VIOLATING: &str = r"# Changelog
+
+Some intro.
+
+## [0.3.0] - 2026-03-01
+
+### Added
+
+- More bull.
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Some bull.
+
+[0.3.0]: https://github.com/owner/repo/compare/v0.1.0...v0.3.0
+[0.1.0]: https://github.com/owner/repo/releases/tag/v0.1.0
+";
+
+const UNPARSABLE: &str = "# Cuntlog\n";
+
+// Each test gets its own directory, as they run concurrently.
+fn write_files(test: &str, files: &[(&str, &str)]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("changelog-check-{}", test));
+    let _ = std::fs::remove_dir_all(&root);
+    for (path, content) in files {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    root
+}
+
+fn check(root: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_changelog"))
+        .current_dir(root)
+        .arg("check")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+mod exit_status {
+    use super::*;
+
+    #[test]
+    fn should_map_failures_to_statuses() {
+        let root = write_files(
+            "status",
+            &[
+                ("CHANGELOG.md", VIOLATING),
+                ("broken/CHANGELOG.md", UNPARSABLE),
+            ],
+        );
+        for (file, status) in [
+            ("CHANGELOG.md", 1),
+            ("broken/CHANGELOG.md", 3),
+            ("missing/CHANGELOG.md", 4),
+        ] {
+            let output = check(&root, &[file]);
+            assert_eq!(output.status.code(), Some(status), "{}: {:?}", file, output);
+        }
+    }
+
+    #[test]
+    fn should_exit_with_most_severe_status_of_all_files() {
+        let root = write_files(
+            "status-aggregated",
+            &[
+                ("CHANGELOG.md", VIOLATING),
+                ("broken/CHANGELOG.md", UNPARSABLE),
+            ],
+        );
+        for (files, status) in [
+            (vec!["CHANGELOG.md", "broken/CHANGELOG.md"], 3),
+            (
+                vec![
+                    "missing/CHANGELOG.md",
+                    "CHANGELOG.md",
+                    "broken/CHANGELOG.md",
+                ],
+                4,
+            ),
+            (vec!["broken/CHANGELOG.md", "CHANGELOG.md"], 3),
+        ] {
+            let output = check(&root, &files);
+            assert_eq!(
+                output.status.code(),
+                Some(status),
+                "{:?}: {:?}",
+                files,
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn should_only_report_through_status_when_quiet() {
+        let root = write_files("status-quiet", &[("CHANGELOG.md", VIOLATING)]);
+        let valid = VIOLATING
+            .replace("## [0.3.0]", "## [0.2.0]")
+            .replace("[0.3.0]:", "[0.2.0]:")
+            .replace("v0.3.0", "v0.2.0");
+        std::fs::write(root.join("valid.md"), valid).unwrap();
+        for (file, status) in [("valid.md", 0), ("CHANGELOG.md", 1)] {
+            let output = check(&root, &["--quiet", file]);
+            assert_eq!(output.status.code(), Some(status), "{}: {:?}", file, output);
+            assert!(output.stdout.is_empty(), "{:?}", output);
+            assert!(output.stderr.is_empty(), "{:?}", output);
+        }
+    }
+}
+
+mod write_baseline {
+    use super::*;
+
+    #[test]
+    fn should_write_baseline_when_some_files_fail() {
+        let root = write_files(
+            "baseline-failing",
+            &[
+                ("CHANGELOG.md", VIOLATING),
+                ("broken/CHANGELOG.md", UNPARSABLE),
+            ],
+        );
+        let output = check(
+            &root,
+            &[
+                "--write-baseline",
+                "baseline.toml",
+                "CHANGELOG.md",
+                "broken/CHANGELOG.md",
+            ],
+        );
+        assert_eq!(output.status.code(), Some(3), "{:?}", output);
+        let baseline = std::fs::read_to_string(root.join("baseline.toml")).unwrap();
+        assert!(baseline.contains("rule = \"version-gap\""), "{}", baseline);
+
+        let output = check(&root, &["--baseline", "baseline.toml", "CHANGELOG.md"]);
+        assert_eq!(output.status.code(), Some(0), "{:?}", output);
+    }
+
+    #[test]
+    fn should_skip_cross_checks_while_recording() {
+        let root = write_files(
+            "baseline-manifest",
+            &[
+                ("CHANGELOG.md", VIOLATING),
+                (
+                    "Cargo.toml",
+                    "[package]\nname = \"bull\"\nversion = \"0.0.1\"\n",
+                ),
+            ],
+        );
+        let output = check(
+            &root,
+            &[
+                "--write-baseline",
+                "baseline.toml",
+                "--manifest",
+                "Cargo.toml",
+                "CHANGELOG.md",
+            ],
+        );
+        assert_eq!(output.status.code(), Some(0), "{:?}", output);
+        assert!(root.join("baseline.toml").exists());
+    }
+}